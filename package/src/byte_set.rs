@@ -0,0 +1,155 @@
+use core::ops::RangeInclusive;
+use crate::pattern::Pattern;
+use crate::scan;
+
+/// Set of bytes backed by a 256-bit table, buildable in `const` context
+/// like `ByteSet::new().range(b'a'..=b'z').bytes(b"_-")`. As a `Pattern`, it matches a byte in the set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// `b'0'..=b'9'`
+    pub const DIGIT:          Self = Self::new().range(b'0'..=b'9');
+    /// `b'0'..=b'9'`, `b'a'..=b'f'` and `b'A'..=b'F'`
+    pub const HEX_DIGIT:      Self = Self::DIGIT.range(b'a'..=b'f').range(b'A'..=b'F');
+    /// `b'a'..=b'z'`
+    pub const LOWERCASE:      Self = Self::new().range(b'a'..=b'z');
+    /// `b'A'..=b'Z'`
+    pub const UPPERCASE:      Self = Self::new().range(b'A'..=b'Z');
+    /// `b'a'..=b'z'` and `b'A'..=b'Z'`
+    pub const ALPHABETIC:     Self = Self::LOWERCASE.union(Self::UPPERCASE);
+    /// `ALPHABETIC` and `DIGIT`
+    pub const ALPHANUMERIC:   Self = Self::ALPHABETIC.union(Self::DIGIT);
+    /// `b' '`, `b'\t'`, `b'\n'`, `b'\x0C'` and `b'\r'`, same as `u8::is_ascii_whitespace`
+    pub const WHITESPACE:     Self = Self::new().bytes(b" \t\n\x0C\r");
+    /// `ALPHABETIC` and `b'_'`
+    pub const IDENT_START:    Self = Self::ALPHABETIC.bytes(b"_");
+    /// `ALPHANUMERIC` and `b'_'`
+    pub const IDENT_CONTINUE: Self = Self::ALPHANUMERIC.bytes(b"_");
+    /// `0x00..=0x7F`
+    pub const ASCII:          Self = Self::new().range(0x00..=0x7F);
+
+    /// Empty set
+    pub const fn new() -> Self {
+        Self([0; 4])
+    }
+    /// Add `byte` into the set
+    pub const fn byte(mut self, byte: u8) -> Self {
+        self.0[(byte >> 6) as usize] |= 1 << (byte & 0b11_1111);
+        self
+    }
+    /// Add all bytes in `range` into the set
+    pub const fn range(mut self, range: RangeInclusive<u8>) -> Self {
+        let (mut b, end) = (*range.start(), *range.end());
+        while b <= end {
+            self = self.byte(b);
+            if b == u8::MAX {break}
+            b += 1
+        }
+        self
+    }
+    /// Add all of `bytes` into the set
+    pub const fn bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.byte(bytes[i]);
+            i += 1
+        }
+        self
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        let [a0, a1, a2, a3] = self.0;
+        let [b0, b1, b2, b3] = other.0;
+        Self([a0 | b0, a1 | b1, a2 | b2, a3 | b3])
+    }
+    pub const fn intersection(self, other: Self) -> Self {
+        let [a0, a1, a2, a3] = self.0;
+        let [b0, b1, b2, b3] = other.0;
+        Self([a0 & b0, a1 & b1, a2 & b2, a3 & b3])
+    }
+    /// Set of all bytes not in the set
+    pub const fn complement(self) -> Self {
+        let [a0, a1, a2, a3] = self.0;
+        Self([!a0, !a1, !a2, !a3])
+    }
+
+    #[inline(always)] pub const fn contains(&self, byte: u8) -> bool {
+        self.0[(byte >> 6) as usize] & (1 << (byte & 0b11_1111)) != 0
+    }
+
+    /// The set as `N` or less inclusive ranges ( padded by repeating the last one ), or `None` if it needs more
+    pub(crate) fn ranges<const N: usize>(&self) -> Option<[(u8, u8); N]> {
+        /* bits where a run of set bits starts / ends */
+        let (mut starts, mut ends) = ([0u64; 4], [0u64; 4]);
+        for i in 0..4 {
+            let prev = if i == 0 {0} else {self.0[i - 1] >> 63};
+            let next = if i == 3 {0} else {self.0[i + 1] << 63};
+            starts[i] = self.0[i] & !((self.0[i] << 1) | prev);
+            ends[i]   = self.0[i] & !((self.0[i] >> 1) | next);
+        }
+
+        let n = starts.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+        if n == 0 || n > N {return None}
+
+        let mut ranges = [(0, 0); N];
+        let (mut s, mut e) = (0, 0);
+        for i in 0..4 {
+            while starts[i] != 0 {
+                ranges[s].0 = (i * 64) as u8 + starts[i].trailing_zeros() as u8;
+                starts[i] &= starts[i] - 1;
+                s += 1
+            }
+            while ends[i] != 0 {
+                ranges[e].1 = (i * 64) as u8 + ends[i].trailing_zeros() as u8;
+                ends[i] &= ends[i] - 1;
+                e += 1
+            }
+        }
+        for i in n..N {ranges[i] = ranges[n - 1]}
+        Some(ranges)
+    }
+}
+
+impl core::ops::BitOr for ByteSet {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+impl core::ops::BitAnd for ByteSet {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+impl core::ops::Not for ByteSet {
+    type Output = Self;
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl Pattern for ByteSet {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        haystack.first().is_some_and(|b| self.contains(*b)).then_some(1)
+    }
+    #[inline] fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        scan::set_len(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        let i = scan::set_len(&self.complement(), haystack);
+        (i < haystack.len()).then_some((i, i + 1))
+    }
+}
+impl Pattern for &ByteSet {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        {**self}.match_prefix(haystack)
+    }
+    #[inline] fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        scan::set_len(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        {**self}.find(haystack)
+    }
+}
@@ -0,0 +1,176 @@
+use core::fmt;
+use crate::location::Location;
+#[cfg(feature="text")] use crate::span::Span;
+
+/// What the reader expected at the point a `expect*` operation failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected<'t> {
+    /// A specific token like `b"datasource"`
+    Token(&'t [u8]),
+    /// One of some tokens like `["provider", "url"]`
+    OneOf(&'t [&'t str]),
+    /// Something described in words like `"unsigned integer"`
+    Description(&'static str),
+}
+
+/// Error returned by `expect*` operations, holding what was expected, what was found and where
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError<'t> {
+    pub expected: Expected<'t>,
+    /// Next byte at the failure point, or `None` if the input is exhausted
+    pub found: Option<u8>,
+    /// Index in the input at the failure point
+    pub index: usize,
+    /// Location of the failure point, or `None` if the reader doesn't track it
+    pub location: Option<Location>,
+}
+
+impl fmt::Display for Expected<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Token(token) => match core::str::from_utf8(token) {
+                Ok(token) => write!(f, "`{token}`"),
+                Err(_)    => write!(f, "{token:?}"),
+            },
+            Self::OneOf(tokens) => {
+                f.write_str("one of ")?;
+                for (i, token) in tokens.iter().enumerate() {
+                    if i > 0 {f.write_str(", ")?}
+                    write!(f, "`{token}`")?
+                }
+                Ok(())
+            }
+            Self::Description(description) => f.write_str(description),
+        }
+    }
+}
+
+impl fmt::Display for ParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}, but found ", self.expected)?;
+        match self.found {
+            None                            => f.write_str("end of input")?,
+            Some(b) if b.is_ascii_graphic() => write!(f, "`{}`", b as char)?,
+            Some(b) if b.is_ascii()         => write!(f, "{:?}", b as char)?,
+            Some(b)                         => write!(f, "0x{b:02x}")?,
+        }
+        match self.location {
+            Some(location) => write!(f, " at {location}"),
+            None           => write!(f, " at index {}", self.index),
+        }
+    }
+}
+
+/// **`text` feature required**\
+/// What went wrong in reading a number
+#[cfg(feature="text")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberErrorKind {
+    /// No digit at the parsing point
+    NoDigits,
+    /// The number doesn't fit in the target type
+    Overflow,
+    /// A digit out of the radix, like `2` in `0b12`
+    InvalidDigit,
+}
+
+/// **`text` feature required**\
+/// Error returned by `read_integer` and `read_integer_literal`, holding what went wrong and where
+#[cfg(feature="text")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberError {
+    pub kind: NumberErrorKind,
+    /// Span of the number literal, or empty span at the parsing point for `NoDigits`
+    pub span: Span,
+}
+
+#[cfg(feature="text")]
+impl fmt::Display for NumberErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NoDigits     => "no digits",
+            Self::Overflow     => "number too large to fit in target type",
+            Self::InvalidDigit => "invalid digit for the radix",
+        })
+    }
+}
+
+#[cfg(feature="text")]
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        match self.span.start_location {
+            Some(location) => write!(f, " at {location}"),
+            None           => write!(f, " at index {}", self.span.start),
+        }
+    }
+}
+
+/// **`text` feature required**\
+/// What went wrong in reading a quoted string
+#[cfg(feature="text")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringErrorKind {
+    /// No opening quote at the parsing point
+    NotQuoted,
+    /// No closing quote until the end of input
+    Unterminated,
+    /// An escape sequence is unknown or out of range
+    InvalidEscape,
+}
+
+/// **`text` feature required**\
+/// Error returned by `read_escaped_by`, holding what went wrong and where
+#[cfg(feature="text")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringError {
+    pub kind: StringErrorKind,
+    /// Span of the invalid escape sequence, the unterminated string, or empty span at the parsing point for `NotQuoted`
+    pub span: Span,
+}
+
+#[cfg(feature="text")]
+impl fmt::Display for StringErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotQuoted     => "expected quoted string",
+            Self::Unterminated  => "unterminated string",
+            Self::InvalidEscape => "invalid escape sequence",
+        })
+    }
+}
+
+#[cfg(feature="text")]
+impl fmt::Display for StringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        match self.span.start_location {
+            Some(location) => write!(f, " at {location}"),
+            None           => write!(f, " at index {}", self.span.start),
+        }
+    }
+}
+
+/// **`text` feature required**\
+/// Error returned by `Escaped::unescape_into` and `Escaped::unescape`
+#[cfg(feature="text")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnescapeError {
+    /// The buffer is shorter than the unescaped bytes
+    BufferTooSmall {
+        /// Length of the unescaped bytes
+        needed: usize,
+    },
+    /// The unescaped bytes are not valid UTF-8
+    InvalidUtf8,
+}
+
+#[cfg(feature="text")]
+impl fmt::Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall { needed } => write!(f, "buffer too small for {needed} unescaped bytes"),
+            Self::InvalidUtf8               => f.write_str("unescaped bytes are not valid UTF-8"),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{Reader, Locator, ByteSet, StringError, StringErrorKind, UnescapeError};
+use crate::scan;
+#[cfg(feature="alloc")] use alloc::{borrow::Cow, string::String};
+
+/// **`text` feature required**\
+/// Escape sequences in a quoted string read by `read_escaped_by`
+///
+/// - `JSON` : `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t`, `\uXXXX` ( with surrogate pairs )
+/// - `RUST` : `\"`, `\'`, `\\`, `\0`, `\n`, `\r`, `\t`, `\x7F`, `\u{10FFFF}`, and `\` at the end of a line skipping the following whitespace
+/// - `C` : `\"`, `\'`, `\\`, `\?`, `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`, `\377` ( octal ), `\xFF`, `\uXXXX`, `\UXXXXXXXX`
+/// - `SQL` : the quote doubled like `'it''s'`
+///
+/// In any dialect but `SQL`, the escape character ( `\` by default ) followed by itself or the quote means the character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escapes {
+    dialect: Dialect,
+    escape:  u8,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {Json, Rust, C, Sql}
+
+impl Escapes {
+    pub const JSON: Self = Self { dialect: Dialect::Json, escape: b'\\' };
+    pub const RUST: Self = Self { dialect: Dialect::Rust, escape: b'\\' };
+    pub const C:    Self = Self { dialect: Dialect::C,    escape: b'\\' };
+    pub const SQL:  Self = Self { dialect: Dialect::Sql,  escape: b'\\' };
+
+    /// Use `escape` instead of `\` as the escape character ( no effect on `SQL` )
+    pub const fn escape_char(mut self, escape: u8) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    #[inline(always)] fn starts_escape(&self, byte: u8, quote: u8) -> bool {
+        match self.dialect {
+            Dialect::Sql => byte == quote,
+            _            => byte == self.escape,
+        }
+    }
+
+    /// Length and meaning of the escape sequence `bytes` starts with, or the length of the invalid sequence
+    fn decode(&self, quote: u8, bytes: &[u8]) -> Result<(usize, Unescaped), usize> {
+        let Some(&code) = bytes.get(1) else {return Err(1)};
+        let hex = |range: core::ops::Range<usize>| bytes.get(range.clone())
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .map(|digits| digits.iter().fold(0, |n, d| n * 16 + (*d as char).to_digit(16).unwrap()))
+            .ok_or(range.end.min(bytes.len()));
+
+        if self.dialect == Dialect::Sql {
+            return if code == quote {Ok((2, Unescaped::Byte(quote)))} else {Err(1)}
+        }
+        if code == self.escape || code == quote {
+            return Ok((2, Unescaped::Byte(code)))
+        }
+
+        let byte = |b: u8| Ok((2, Unescaped::Byte(b)));
+        match (self.dialect, code) {
+            (_, b'n') => byte(b'\n'),
+            (_, b'r') => byte(b'\r'),
+            (_, b't') => byte(b'\t'),
+            (_, b'"' | b'\\') => byte(code),
+            (Dialect::Json, b'/') => byte(b'/'),
+            (Dialect::Json | Dialect::C, b'b') => byte(0x08),
+            (Dialect::Json | Dialect::C, b'f') => byte(0x0C),
+            (Dialect::Rust | Dialect::C, b'\'') => byte(b'\''),
+
+            (Dialect::Json, b'u') => match hex(2..6)? {
+                high @ 0xD800..=0xDBFF => {
+                    if bytes.get(6) != Some(&self.escape) || bytes.get(7) != Some(&b'u') {return Err(6)}
+                    match hex(8..12)? {
+                        low @ 0xDC00..=0xDFFF => {
+                            let c = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                            Ok((12, Unescaped::Char(char::from_u32(c).ok_or(12usize)?)))
+                        }
+                        _ => Err(12),
+                    }
+                }
+                c => Ok((6, Unescaped::Char(char::from_u32(c).ok_or(6usize)?))),
+            },
+
+            (Dialect::Rust, b'0') => byte(0),
+            (Dialect::Rust, b'x') => match hex(2..4)? {
+                b @ 0..=0x7F => Ok((4, Unescaped::Byte(b as u8))),
+                _ => Err(4),
+            },
+            (Dialect::Rust, b'u') => {
+                if bytes.get(2) != Some(&b'{') {return Err(2)}
+                let close = 3 + bytes[3..].iter().take_while(|b| b.is_ascii_hexdigit() || **b == b'_').count();
+                if bytes.get(close) != Some(&b'}') {return Err(close.min(bytes.len()))}
+                let digits = || bytes[3..close].iter().filter(|d| **d != b'_');
+                if !(1..=6).contains(&digits().count()) || bytes[3] == b'_' {return Err(close + 1)}
+                let c = digits().fold(0, |n, d| n * 16 + (*d as char).to_digit(16).unwrap());
+                Ok((close + 1, Unescaped::Char(char::from_u32(c).ok_or(close + 1)?)))
+            }
+            (Dialect::Rust, b'\n' | b'\r') => {
+                let len = if bytes[1..].starts_with(b"\r\n") {3} else if code == b'\n' {2} else {return Err(2)};
+                Ok((len + scan::whitespace_len(&bytes[len..]), Unescaped::Nothing))
+            }
+
+            (Dialect::C, b'a') => byte(0x07),
+            (Dialect::C, b'v') => byte(0x0B),
+            (Dialect::C, b'?') => byte(b'?'),
+            (Dialect::C, b'0'..=b'7') => {
+                let len = 1 + bytes[1..].iter().take(3).take_while(|b| matches!(b, b'0'..=b'7')).count();
+                match bytes[1..len].iter().fold(0u32, |n, d| n * 8 + (d - b'0') as u32) {
+                    b @ 0..=0xFF => Ok((len, Unescaped::Byte(b as u8))),
+                    _ => Err(len),
+                }
+            }
+            (Dialect::C, b'x') => match 2 + bytes[2..].iter().take_while(|b| b.is_ascii_hexdigit()).count() {
+                len @ (3 | 4) => Ok((len, Unescaped::Byte(hex(2..len)? as u8))),
+                len => Err(len),
+            },
+            (Dialect::C, b'u') => Ok((6, Unescaped::Char(char::from_u32(hex(2..6)?).ok_or(6usize)?))),
+            (Dialect::C, b'U') => Ok((10, Unescaped::Char(char::from_u32(hex(2..10)?).ok_or(10usize)?))),
+
+            _ => Err(2),
+        }
+    }
+}
+
+/// What an escape sequence means
+enum Unescaped {
+    Byte(u8),
+    Char(char),
+    Nothing,
+}
+
+/// **`text` feature required**\
+/// Quoted string read by `read_escaped_by`, holding the raw bytes between the quotes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escaped<'r> {
+    raw:         &'r [u8],
+    quote:       u8,
+    escapes:     Escapes,
+    has_escapes: bool,
+}
+impl<'r> Escaped<'r> {
+    /// Bytes between the quotes as written in the input, with the escape sequences
+    #[inline(always)] pub const fn raw(&self) -> &'r [u8] {
+        self.raw
+    }
+    /// Whether the raw bytes have any escape sequence, or else they are already unescaped
+    #[inline(always)] pub const fn has_escapes(&self) -> bool {
+        self.has_escapes
+    }
+    /// Iterator of the unescaped bytes ( characters by escape sequences are encoded in UTF-8 )
+    #[inline] pub const fn unescaped(&self) -> Unescape<'r> {
+        Unescape { rest: self.raw, quote: self.quote, escapes: self.escapes, utf8: [0; 4], utf8_pos: 0, utf8_len: 0 }
+    }
+
+    /// Write the unescaped bytes into `buf`, then return them as `str`
+    ///
+    /// Or, returns `UnescapeError` if `buf` is too small ( with the needed length ), or if they are not valid UTF-8
+    pub fn unescape_into<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, UnescapeError> {
+        let mut len = 0;
+        for b in self.unescaped() {
+            let Some(slot) = buf.get_mut(len) else {
+                return Err(UnescapeError::BufferTooSmall { needed: self.unescaped().count() })
+            };
+            *slot = b;
+            len += 1
+        }
+        core::str::from_utf8(&buf[..len]).map_err(|_| UnescapeError::InvalidUtf8)
+    }
+}
+
+#[cfg(feature="alloc")]
+impl<'r> Escaped<'r> {
+    /// **`alloc` feature required**\
+    /// Unescaped bytes, borrowing the raw bytes if no escape sequence, or else allocating
+    pub fn unescape_bytes(&self) -> Cow<'r, [u8]> {
+        if self.has_escapes {
+            Cow::Owned(self.unescaped().collect())
+        } else {
+            Cow::Borrowed(self.raw)
+        }
+    }
+    /// **`alloc` feature required**\
+    /// Unescaped string, borrowing the raw bytes if no escape sequence, or else allocating
+    ///
+    /// Or, returns `UnescapeError::InvalidUtf8` if they are not valid UTF-8
+    pub fn unescape(&self) -> Result<Cow<'r, str>, UnescapeError> {
+        match self.unescape_bytes() {
+            Cow::Borrowed(bytes) => core::str::from_utf8(bytes).map(Cow::Borrowed).map_err(|_| UnescapeError::InvalidUtf8),
+            Cow::Owned(bytes)    => String::from_utf8(bytes).map(Cow::Owned).map_err(|_| UnescapeError::InvalidUtf8),
+        }
+    }
+}
+
+/// **`text` feature required**\
+/// Iterator of the unescaped bytes of an `Escaped`, decoding the escape sequences one by one without allocating
+#[derive(Debug, Clone)]
+pub struct Unescape<'r> {
+    rest:     &'r [u8],
+    quote:    u8,
+    escapes:  Escapes,
+    /// rest of a character by an escape sequence
+    utf8:     [u8; 4],
+    utf8_pos: u8,
+    utf8_len: u8,
+}
+impl Iterator for Unescape<'_> {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        if self.utf8_pos < self.utf8_len {
+            self.utf8_pos += 1;
+            return Some(self.utf8[self.utf8_pos as usize - 1])
+        }
+        loop {
+            let (&b, rest) = self.rest.split_first()?;
+            if !self.escapes.starts_escape(b, self.quote) {
+                self.rest = rest;
+                return Some(b)
+            }
+            /* validated in reading */
+            let (len, unescaped) = self.escapes.decode(self.quote, self.rest).ok()?;
+            self.rest = &self.rest[len..];
+            match unescaped {
+                Unescaped::Byte(b) => return Some(b),
+                Unescaped::Char(c) => {
+                    self.utf8_len = c.encode_utf8(&mut self.utf8).len() as u8;
+                    self.utf8_pos = 1;
+                    return Some(self.utf8[0])
+                }
+                Unescaped::Nothing => continue,
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = (self.utf8_len - self.utf8_pos) as usize;
+        (pending, Some(pending + self.rest.len()))
+    }
+}
+
+impl<'r, L: Locator> Reader<'r, L> {
+    /// **`text` feature required**\
+    /// Read a string quoted by `quote` with the escape sequences of `escapes`, like `"say \"hi\""` with `Escapes::JSON`,
+    /// then return the raw bytes between the quotes, to be unescaped by `Escaped::unescaped` without allocating.
+    ///
+    /// Or, returns `StringError` without reading anything if not starting with `quote`,
+    /// if the closing quote is not found, or if an escape sequence is invalid ( with its span ).
+    pub fn read_escaped_by(&mut self, quote: u8, escapes: Escapes) -> Result<Escaped<'r>, StringError> {
+        let start = self.checkpoint();
+        let error = |this: &mut Self, kind, offset: usize, len: usize| {
+            this.advance_unchecked_by(offset);
+            let from = this.checkpoint();
+            this.advance_unchecked_by(len);
+            let span = this.span_since(from);
+            this.restore(start);
+            Err(StringError { kind, span })
+        };
+
+        let input = unsafe {self.buf.get_unchecked(self.index..)};
+        if input.first() != Some(&quote) {return error(self, StringErrorKind::NotQuoted, 0, 0)}
+        let content = &input[1..];
+
+        let plain = ByteSet::new().byte(quote).byte(escapes.escape).complement();
+        let (mut i, mut has_escapes) = (0, false);
+        loop {
+            i += scan::set_len(&plain, &content[i..]);
+            let Some(&b) = content.get(i) else {
+                return error(self, StringErrorKind::Unterminated, 0, input.len())
+            };
+            if b == quote && !(escapes.dialect == Dialect::Sql && content.get(i + 1) == Some(&quote)) {
+                break
+            }
+            if !escapes.starts_escape(b, quote) {
+                i += 1;
+                continue
+            }
+            match escapes.decode(quote, &content[i..]) {
+                Ok((len, _)) => {i += len; has_escapes = true}
+                /* an invalid sequence like `\u12"` ends at the closing quote */
+                Err(len) => return match content[i + 1..].iter().position(|b| *b == quote) {
+                    Some(q) => error(self, StringErrorKind::InvalidEscape, 1 + i, len.min(1 + q)),
+                    None    => error(self, StringErrorKind::Unterminated, 0, input.len()),
+                },
+            }
+        }
+
+        self.advance_unchecked_by(1 + i + 1);
+        Ok(Escaped { raw: &content[..i], quote, escapes, has_escapes })
+    }
+}
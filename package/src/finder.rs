@@ -0,0 +1,163 @@
+use crate::pattern::Pattern;
+
+/// Precompiled substring searcher for `needle`, reusable across many `read_until`s
+///
+/// Searches by the Two-Way algorithm in O(n + m) time and O(1) space, skipping
+/// by the whole needle length where the haystack has a byte not in the needle.
+/// Built by `new_ignore_ascii_case`, it compares bytes ASCII case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Finder<'n> {
+    needle:      &'n [u8],
+    /// critical factorization `needle[..crit_pos]`, `needle[crit_pos..]`
+    crit_pos:    usize,
+    period:      usize,
+    /// bit `b & 63` is set for every byte `b` in ( the period of ) the needle
+    byteset:     u64,
+    long_period: bool,
+    ignore_case: bool,
+}
+
+impl<'n> Finder<'n> {
+    pub const fn new(needle: &'n [u8]) -> Self {
+        Self::build(needle, false)
+    }
+    pub const fn new_ignore_ascii_case(needle: &'n [u8]) -> Self {
+        Self::build(needle, true)
+    }
+    const fn build(needle: &'n [u8], ignore_case: bool) -> Self {
+        let (crit_pos_less,    period_less)    = maximal_suffix(needle, false, ignore_case);
+        let (crit_pos_greater, period_greater) = maximal_suffix(needle, true, ignore_case);
+        let (crit_pos, period) = if crit_pos_less > crit_pos_greater {
+            (crit_pos_less, period_less)
+        } else {
+            (crit_pos_greater, period_greater)
+        };
+
+        let mut is_periodic = period + crit_pos <= needle.len();
+        let mut i = 0; while is_periodic && i < crit_pos {
+            is_periodic = fold(needle[i], ignore_case) == fold(needle[period + i], ignore_case);
+            i += 1
+        }
+
+        if is_periodic {
+            Self { needle, crit_pos, period, byteset: byteset(needle, period, ignore_case), long_period: false, ignore_case }
+        } else {
+            let period = if crit_pos > needle.len() - crit_pos {crit_pos} else {needle.len() - crit_pos} + 1;
+            Self { needle, crit_pos, period, byteset: byteset(needle, needle.len(), ignore_case), long_period: true, ignore_case }
+        }
+    }
+
+    #[inline(always)] pub const fn needle(&self) -> &'n [u8] {
+        self.needle
+    }
+
+    /// Length of the needle if `haystack` starts with it, or `None` if not
+    #[inline] fn match_prefix_len(&self, haystack: &[u8]) -> Option<usize> {
+        let n = self.needle.len();
+        let matches = match haystack.get(..n) {
+            None         => false,
+            Some(prefix) => if self.ignore_case {prefix.eq_ignore_ascii_case(self.needle)} else {prefix == self.needle},
+        };
+        matches.then_some(n)
+    }
+
+    /// Index of the first occurrence of the needle in `haystack`, or `None` if not found
+    ///
+    /// As a `Pattern`, `Finder` finds the start and end of the occurrence instead.
+    pub fn find_index(&self, haystack: &[u8]) -> Option<usize> {
+        let (needle, ignore_case) = (self.needle, self.ignore_case);
+        match needle.len() {
+            0 => return Some(0),
+            1 if !ignore_case => return haystack.iter().position(|b| *b == needle[0]),
+            _ => if needle.len() > haystack.len() {return None},
+        }
+
+        let last = needle.len() - 1;
+        let (mut position, mut memory) = (0, 0);
+        'search: while position + last < haystack.len() {
+            let tail = fold(unsafe {*haystack.get_unchecked(position + last)}, ignore_case);
+            if (self.byteset >> (tail & 0b11_1111)) & 1 == 0 {
+                position += needle.len();
+                memory = 0;
+                continue 'search
+            }
+
+            let start = if self.long_period {self.crit_pos} else {self.crit_pos.max(memory)};
+            for i in start..needle.len() {
+                if unsafe {fold(*needle.get_unchecked(i), ignore_case) != fold(*haystack.get_unchecked(position + i), ignore_case)} {
+                    position += i - self.crit_pos + 1;
+                    memory = 0;
+                    continue 'search
+                }
+            }
+
+            let start = if self.long_period {0} else {memory};
+            for i in (start..self.crit_pos).rev() {
+                if unsafe {fold(*needle.get_unchecked(i), ignore_case) != fold(*haystack.get_unchecked(position + i), ignore_case)} {
+                    position += self.period;
+                    if !self.long_period {memory = needle.len() - self.period}
+                    continue 'search
+                }
+            }
+
+            return Some(position)
+        }
+        None
+    }
+}
+
+#[inline(always)] const fn fold(b: u8, ignore_case: bool) -> u8 {
+    if ignore_case {b.to_ascii_lowercase()} else {b}
+}
+
+/// Start and period of the maximal suffix of `needle` in lexicographic order ( reversed if `greater` )
+const fn maximal_suffix(needle: &[u8], greater: bool, ignore_case: bool) -> (usize, usize) {
+    let (mut left, mut right, mut offset, mut period) = (0, 1, 0, 1);
+    while right + offset < needle.len() {
+        let (a, b) = (fold(needle[right + offset], ignore_case), fold(needle[left + offset], ignore_case));
+        if (a < b && !greater) || (a > b && greater) {
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+const fn byteset(needle: &[u8], len: usize, ignore_case: bool) -> u64 {
+    let mut set = 0;
+    let mut i = 0; while i < len {
+        set |= 1 << (fold(needle[i], ignore_case) & 0b11_1111);
+        i += 1
+    }
+    set
+}
+
+impl Pattern for Finder<'_> {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.match_prefix_len(haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        self.find_index(haystack).map(|i| (i, i + self.needle.len()))
+    }
+}
+impl Pattern for &Finder<'_> {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.match_prefix_len(haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        self.find_index(haystack).map(|i| (i, i + self.needle.len()))
+    }
+}
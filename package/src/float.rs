@@ -0,0 +1,536 @@
+use crate::{Reader, Locator, IdentClass, NumberError, NumberErrorKind};
+use crate::pow5::{SMALLEST_POWER_OF_FIVE, LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, LEFT_SHIFT, LEFT_SHIFT_POW5};
+
+mod sealed {pub trait Sealed {}}
+
+/// **`text` feature required**\
+/// Which floating-point literals `read_float` accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatSyntax {
+    /// JSON numbers : `-0.5`, `1e-9`, `42`, with no `+`, `.5`, `5.`, leading zeros, `inf` or `NaN`
+    Json,
+    /// Rust literals with an optional sign : `3.14`, `-1e-9`, `1_000.5`, `5.`, `2f32`, and `inf`, `infinity`, `NaN`
+    /// ( case-insensitively, like `str::parse` )
+    Rust,
+    /// C literals with an optional sign : `3.14`, `-1e-9`, `.5`, `5.`, `1.5f`, and `inf`, `infinity`, `NaN`
+    /// ( case-insensitively, like `strtod` ), without hexadecimal ones
+    C,
+}
+
+/// **`text` feature required**\
+/// Primitive floating-point types `read_float` reads ( `f32`, `f64` )
+pub trait Float: Copy + sealed::Sealed {
+    #[doc(hidden)] const MANTISSA_EXPLICIT_BITS: i32;
+    #[doc(hidden)] const MINIMUM_EXPONENT: i32;
+    #[doc(hidden)] const INFINITE_POWER: i32;
+    #[doc(hidden)] const SMALLEST_POWER_OF_TEN: i64;
+    #[doc(hidden)] const LARGEST_POWER_OF_TEN: i64;
+    #[doc(hidden)] const MIN_EXPONENT_ROUND_TO_EVEN: i64;
+    #[doc(hidden)] const MAX_EXPONENT_ROUND_TO_EVEN: i64;
+    #[doc(hidden)] const MIN_EXPONENT_FAST_PATH: i64;
+    #[doc(hidden)] const MAX_EXPONENT_FAST_PATH: i64;
+    #[doc(hidden)] const MAX_EXPONENT_DISGUISED_FAST_PATH: i64;
+    #[doc(hidden)] const INFINITY: Self;
+    #[doc(hidden)] const NAN: Self;
+    #[doc(hidden)] fn from_u64(mantissa: u64) -> Self;
+    /// `10^exponent`, exact for `exponent <= MAX_EXPONENT_FAST_PATH`
+    #[doc(hidden)] fn pow10(exponent: usize) -> Self;
+    #[doc(hidden)] fn from_biased(fp: BiasedFp) -> Self;
+    #[doc(hidden)] fn mul(self, rhs: Self) -> Self;
+    #[doc(hidden)] fn div(self, rhs: Self) -> Self;
+    #[doc(hidden)] fn neg(self) -> Self;
+}
+macro_rules! float {
+    ($($t:ty: $bits:ty, $mantissa:literal, $min_exp:literal, $inf_power:literal, $ten:literal..=$max_ten:literal,
+        round_to_even $rte:literal..=$max_rte:literal, fast_path $fast:literal..=$max_fast:literal, disguised $disguised:literal;)*) => {$(
+        impl sealed::Sealed for $t {}
+        impl Float for $t {
+            const MANTISSA_EXPLICIT_BITS: i32 = $mantissa;
+            const MINIMUM_EXPONENT: i32 = $min_exp;
+            const INFINITE_POWER: i32 = $inf_power;
+            const SMALLEST_POWER_OF_TEN: i64 = $ten;
+            const LARGEST_POWER_OF_TEN: i64 = $max_ten;
+            const MIN_EXPONENT_ROUND_TO_EVEN: i64 = $rte;
+            const MAX_EXPONENT_ROUND_TO_EVEN: i64 = $max_rte;
+            const MIN_EXPONENT_FAST_PATH: i64 = $fast;
+            const MAX_EXPONENT_FAST_PATH: i64 = $max_fast;
+            const MAX_EXPONENT_DISGUISED_FAST_PATH: i64 = $disguised;
+            const INFINITY: Self = <$t>::INFINITY;
+            const NAN: Self = <$t>::NAN;
+            #[inline(always)] fn from_u64(mantissa: u64) -> Self {mantissa as Self}
+            #[inline] fn pow10(exponent: usize) -> Self {
+                const TABLE: [$t; $max_fast + 1] = {
+                    let mut table = [1.; $max_fast + 1];
+                    let mut i = 1; while i < table.len() {table[i] = table[i - 1] * 10.; i += 1}
+                    table
+                };
+                TABLE[exponent]
+            }
+            #[inline(always)] fn from_biased(fp: BiasedFp) -> Self {
+                <$t>::from_bits((fp.f | (fp.e as u64) << $mantissa) as $bits)
+            }
+            #[inline(always)] fn mul(self, rhs: Self) -> Self {self * rhs}
+            #[inline(always)] fn div(self, rhs: Self) -> Self {self / rhs}
+            #[inline(always)] fn neg(self) -> Self {-self}
+        }
+    )*};
+}
+float! {
+    f32: u32, 23, -127, 0xFF, -65..=38, round_to_even -17..=10, fast_path -10..=10, disguised 17;
+    f64: u64, 52, -1023, 0x7FF, -342..=308, round_to_even -4..=23, fast_path -22..=22, disguised 37;
+}
+
+/// Binary float with mantissa `f` and biased exponent `e`, or `e < 0` if not determined
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BiasedFp {f: u64, e: i32}
+impl BiasedFp {
+    const fn zero_pow2(e: i32) -> Self {Self { f: 0, e }}
+}
+
+/// Decimal literal split into its parts, where `integer` and `fraction` may have `_`s
+struct Literal<'a> {
+    negative: bool,
+    integer:  &'a [u8],
+    fraction: &'a [u8],
+    exponent: i64,
+}
+
+/// Exponents beyond this are saturated to this, enough for any float to be zero or infinity
+pub(crate) const MAX_EXPONENT: i64 = 0x10000;
+
+impl<'r, L: Locator> Reader<'r, L> {
+    /// **`text` feature required**\
+    /// Read a floating-point literal like `3.14`, `-1e-9`, `.5`, `inf`, `NaN` in `syntax` as `T`,
+    /// correctly rounded to the nearest ( ties to even ) without allocating.
+    ///
+    /// The longest literal of `syntax` is read; a too large or too small one is read as infinity or zero like `str::parse`.
+    ///
+    /// Or, returns `NumberError` without reading anything if no literal is found.
+    pub fn read_float<T: Float>(&mut self, syntax: FloatSyntax) -> Result<T, NumberError> {
+        let input = unsafe {self.buf.get_unchecked(self.index..)};
+        let (negative, sign) = scan_sign(input, syntax);
+
+        if syntax != FloatSyntax::Json {
+            if let Some((len, value)) = special::<T>(&input[sign..]) {
+                self.advance_unchecked_by(sign + len);
+                return Ok(if negative {value.neg()} else {value})
+            }
+        }
+        match scan(&input[sign..], syntax) {
+            Some((len, integer, fraction, exponent)) => {
+                self.advance_unchecked_by(sign + len);
+                Ok(convert(Literal { negative, integer, fraction, exponent }))
+            }
+            None => Err(NumberError { kind: NumberErrorKind::NoDigits, span: self.span_since(self.checkpoint()) }),
+        }
+    }
+}
+
+/// `inf`, `infinity` or `nan` case-insensitively as a whole word
+fn special<T: Float>(input: &[u8]) -> Option<(usize, T)> {
+    let (len, value) = [(&b"infinity"[..], T::INFINITY), (b"inf", T::INFINITY), (b"nan", T::NAN)].into_iter()
+        .find(|(word, _)| input.get(..word.len()).is_some_and(|head| head.eq_ignore_ascii_case(word)))
+        .map(|(word, value)| (word.len(), value))?;
+    IdentClass::Ascii.is_word_end(input, len).then_some((len, value))
+}
+
+/// Whether the literal is negative, and the length of its sign
+pub(crate) fn scan_sign(input: &[u8], syntax: FloatSyntax) -> (bool, usize) {
+    match input.first() {
+        Some(b'-')                                => (true, 1),
+        Some(b'+') if syntax != FloatSyntax::Json => (false, 1),
+        _                                         => (false, 0),
+    }
+}
+
+/// Length, integer digits, fraction digits and exponent of the longest unsigned literal of `syntax`
+pub(crate) fn scan(input: &[u8], syntax: FloatSyntax) -> Option<(usize, &[u8], &[u8], i64)> {
+    let at = |i: usize| input.get(i).copied();
+    let digits = |start: usize, separator: bool| start + input[start..].iter()
+        .take_while(|b| b.is_ascii_digit() || (separator && **b == b'_')).count();
+
+    let (rust, json) = (syntax == FloatSyntax::Rust, syntax == FloatSyntax::Json);
+
+    let integer_end = match at(0) {
+        Some(b'0') if json                     => 1,
+        Some(b'0'..=b'9')                      => digits(0, rust),
+        Some(b'.') if syntax == FloatSyntax::C => 0,
+        _ => return None,
+    };
+
+    let mut end = integer_end;
+    let mut fraction = &input[end..end];
+    if at(end) == Some(b'.') {
+        let fraction_end = match at(end + 1) {
+            Some(b'0'..=b'9') => Some(digits(end + 1, rust)),
+            /* `5.` */
+            next if rust => (integer_end > 0 && !matches!(next, Some(b'.' | b'_' | b'a'..=b'z' | b'A'..=b'Z' | 0x80..))).then_some(end + 1),
+            _    if !json => (integer_end > 0).then_some(end + 1),
+            _ => None,
+        };
+        if let Some(fraction_end) = fraction_end {
+            fraction = &input[end + 1..fraction_end];
+            end = fraction_end
+        }
+    }
+    if integer_end == 0 && fraction.is_empty() {return None}
+    let has_point = end > integer_end;
+
+    let (mut exponent, mut has_exponent) = (0i64, false);
+    if matches!(at(end), Some(b'e' | b'E')) {
+        let sign = matches!(at(end + 1), Some(b'+' | b'-')) as usize;
+        let start = end + 1 + sign;
+        let separators = if rust {input[start..].iter().take_while(|b| **b == b'_').count()} else {0};
+        if at(start + separators).is_some_and(|b| b.is_ascii_digit()) {
+            let exponent_end = digits(start, rust);
+            for d in &input[start..exponent_end] {
+                if *d != b'_' {exponent = (exponent * 10 + (d - b'0') as i64).min(MAX_EXPONENT)}
+            }
+            if at(end + 1) == Some(b'-') {exponent = -exponent}
+            (end, has_exponent) = (exponent_end, true)
+        }
+    }
+
+    let suffix = match syntax {
+        FloatSyntax::Rust => [&b"f32"[..], b"f64"].into_iter()
+            .find(|suffix| input[end..].starts_with(suffix) && IdentClass::Ascii.is_word_end(&input[end..], suffix.len()))
+            .map_or(0, |suffix| suffix.len()),
+        FloatSyntax::C if has_point || has_exponent => match at(end) {
+            Some(b'f' | b'F' | b'l' | b'L') if IdentClass::Ascii.is_word_end(&input[end..], 1) => 1,
+            _ => 0,
+        },
+        _ => 0,
+    };
+
+    Some((end + suffix, &input[..integer_end], fraction, exponent))
+}
+
+fn convert<T: Float>(literal: Literal) -> T {
+    let digits = || literal.integer.iter().chain(literal.fraction).filter(|b| **b != b'_').map(|b| b - b'0');
+    let n_fraction = literal.fraction.iter().filter(|b| **b != b'_').count() as i64;
+
+    /* the first 19 significant digits as `mantissa * 10^exponent` */
+    let (mut mantissa, mut n_significant) = (0u64, 0i64);
+    for d in digits().skip_while(|d| *d == 0) {
+        if n_significant < 19 {mantissa = mantissa * 10 + d as u64}
+        n_significant += 1
+    }
+    let many_digits = n_significant > 19;
+    let exponent = literal.exponent - n_fraction + (n_significant - 19).max(0);
+
+    let value = match fast_path::<T>(mantissa, exponent, many_digits) {
+        Some(value) => value,
+        None => {
+            let mut fp = eisel_lemire::<T>(exponent, mantissa);
+            if many_digits && fp.e >= 0 && fp != eisel_lemire::<T>(exponent, mantissa + 1) {
+                fp.e = -1
+            }
+            if fp.e < 0 {
+                fp = Decimal::new(digits(), n_fraction, literal.exponent).into_float::<T>()
+            }
+            T::from_biased(fp)
+        }
+    };
+    if literal.negative {value.neg()} else {value}
+}
+
+/// Clinger's fast path, exact by one float multiplication or division when both operands are exact
+fn fast_path<T: Float>(mantissa: u64, exponent: i64, many_digits: bool) -> Option<T> {
+    let max_mantissa = 2u64 << T::MANTISSA_EXPLICIT_BITS;
+    if many_digits || mantissa > max_mantissa
+    || !(T::MIN_EXPONENT_FAST_PATH..=T::MAX_EXPONENT_DISGUISED_FAST_PATH).contains(&exponent) {
+        return None
+    }
+
+    if exponent <= T::MAX_EXPONENT_FAST_PATH {
+        let value = T::from_u64(mantissa);
+        Some(if exponent < 0 {value.div(T::pow10(-exponent as usize))} else {value.mul(T::pow10(exponent as usize))})
+    } else {
+        /* `12e30` as `12000...0e22` */
+        let mantissa = mantissa.checked_mul(10u64.pow((exponent - T::MAX_EXPONENT_FAST_PATH) as u32))?;
+        (mantissa <= max_mantissa).then(|| T::from_u64(mantissa).mul(T::pow10(T::MAX_EXPONENT_FAST_PATH as usize)))
+    }
+}
+
+/// Eisel-Lemire algorithm computing `w * 10^q` by a 128-bit approximation of `5^q`,
+/// or `BiasedFp` with `e < 0` if the approximation can't decide the rounding
+fn eisel_lemire<T: Float>(q: i64, mut w: u64) -> BiasedFp {
+    if w == 0 || q < T::SMALLEST_POWER_OF_TEN {
+        return BiasedFp::zero_pow2(0)
+    } else if q > T::LARGEST_POWER_OF_TEN {
+        return BiasedFp::zero_pow2(T::INFINITE_POWER)
+    }
+
+    let lz = w.leading_zeros();
+    w <<= lz;
+    let (lo, hi) = product_approx(q, w, T::MANTISSA_EXPLICIT_BITS + 3);
+    if lo == u64::MAX && !(-27..=55).contains(&q) {
+        /* `+1` of the approximation may cross the halfway point */
+        return BiasedFp::zero_pow2(-1)
+    }
+
+    let upperbit = (hi >> 63) as i32;
+    let mut mantissa = hi >> (upperbit + 64 - T::MANTISSA_EXPLICIT_BITS - 3);
+    let mut power2 = power(q as i32) + upperbit - lz as i32 - T::MINIMUM_EXPONENT;
+    if power2 <= 0 {
+        /* subnormal */
+        if -power2 + 1 >= 64 {
+            return BiasedFp::zero_pow2(0)
+        }
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        power2 = (mantissa >= (1 << T::MANTISSA_EXPLICIT_BITS)) as i32;
+        return BiasedFp { f: mantissa, e: power2 }
+    }
+
+    if lo <= 1
+    && (T::MIN_EXPONENT_ROUND_TO_EVEN..=T::MAX_EXPONENT_ROUND_TO_EVEN).contains(&q)
+    && mantissa & 3 == 1
+    && (mantissa << (upperbit + 64 - T::MANTISSA_EXPLICIT_BITS - 3)) == hi {
+        /* exactly halfway, round down to even */
+        mantissa &= !1
+    }
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= (2 << T::MANTISSA_EXPLICIT_BITS) {
+        mantissa = 1 << T::MANTISSA_EXPLICIT_BITS;
+        power2 += 1
+    }
+    mantissa &= !(1 << T::MANTISSA_EXPLICIT_BITS);
+    if power2 >= T::INFINITE_POWER {
+        return BiasedFp::zero_pow2(T::INFINITE_POWER)
+    }
+    BiasedFp { f: mantissa, e: power2 }
+}
+
+/// `floor(log2(10^q)) + 63`
+#[inline(always)] fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+/// ( low, high ) 64 bits of `w * 5^q`, computed exactly enough for the high `precision` bits
+#[inline] fn product_approx(q: i64, w: u64, precision: i32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+    let (hi5, lo5) = POWER_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+    debug_assert!((SMALLEST_POWER_OF_FIVE..=LARGEST_POWER_OF_FIVE).contains(&q));
+
+    let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+    if first_hi & mask == mask {
+        let (_, second_hi) = full_multiplication(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {first_hi += 1}
+    }
+    (first_lo, first_hi)
+}
+#[inline(always)] fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    let r = (a as u128) * (b as u128);
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Arbitrary-precision decimal `0.digits * 10^decimal_point` for the slow path,
+/// keeping enough digits to decide the rounding of any float
+struct Decimal {
+    n_digits:      usize,
+    decimal_point: i32,
+    /// whether some non-zero digits are dropped
+    truncated:     bool,
+    digits:        [u8; Decimal::MAX_DIGITS],
+}
+impl Decimal {
+    const MAX_DIGITS: usize = 768;
+    const DECIMAL_POINT_RANGE: i32 = 2047;
+
+    fn new(digits: impl Iterator<Item = u8>, n_fraction: i64, exponent: i64) -> Self {
+        let mut this = Self { n_digits: 0, decimal_point: 0, truncated: false, digits: [0; Self::MAX_DIGITS] };
+        let (mut n, mut n_until_nonzero) = (0usize, 0usize);
+        for d in digits.skip_while(|d| *d == 0) {
+            if n < Self::MAX_DIGITS {this.digits[n] = d}
+            n += 1;
+            if d != 0 {n_until_nonzero = n}
+        }
+        if n_until_nonzero > Self::MAX_DIGITS {
+            this.truncated = true
+        }
+        this.n_digits = n_until_nonzero.min(Self::MAX_DIGITS);
+        let range = (Self::DECIMAL_POINT_RANGE as i64) * 2;
+        this.decimal_point = (n as i64 - n_fraction + exponent).clamp(-range, range) as i32;
+        this
+    }
+
+    fn trim(&mut self) {
+        while self.n_digits != 0 && self.digits[self.n_digits - 1] == 0 {self.n_digits -= 1}
+    }
+
+    /// Integer part rounded to the nearest, ties to even
+    fn round(&self) -> u64 {
+        if self.n_digits == 0 || self.decimal_point < 0 {
+            return 0
+        } else if self.decimal_point > 18 {
+            return u64::MAX
+        }
+        let point = self.decimal_point as usize;
+        let mut n = 0u64;
+        for i in 0..point {
+            n = n * 10 + if i < self.n_digits {self.digits[i] as u64} else {0}
+        }
+        let round_up = point < self.n_digits && match self.digits[point] {
+            5 if point + 1 == self.n_digits => self.truncated || (point != 0 && self.digits[point - 1] & 1 != 0),
+            d => d >= 5,
+        };
+        n + round_up as u64
+    }
+
+    /// Multiply by `2^shift` ( `shift <= 60` )
+    fn left_shift(&mut self, shift: usize) {
+        if self.n_digits == 0 {return}
+        let n_new_digits = self.n_new_digits_by_left_shift(shift);
+        let (mut read, mut write) = (self.n_digits, self.n_digits + n_new_digits);
+        let mut n = 0u64;
+        while read != 0 {
+            read -= 1;
+            write -= 1;
+            n += (self.digits[read] as u64) << shift;
+            self.put_digit(write, (n % 10) as u8);
+            n /= 10
+        }
+        while n > 0 {
+            write -= 1;
+            self.put_digit(write, (n % 10) as u8);
+            n /= 10
+        }
+        self.n_digits = (self.n_digits + n_new_digits).min(Self::MAX_DIGITS);
+        self.decimal_point += n_new_digits as i32;
+        self.trim()
+    }
+    #[inline(always)] fn put_digit(&mut self, index: usize, digit: u8) {
+        if index < Self::MAX_DIGITS {self.digits[index] = digit} else if digit > 0 {self.truncated = true}
+    }
+    fn n_new_digits_by_left_shift(&self, shift: usize) -> usize {
+        let (a, b) = (LEFT_SHIFT[shift], LEFT_SHIFT[shift + 1]);
+        let n_new_digits = (a >> 11) as usize;
+        let pow5 = &LEFT_SHIFT_POW5[(a & 0x7FF) as usize..(b & 0x7FF) as usize];
+        /* one less new digit if the digits are less than those of `5^shift` */
+        for (i, p) in pow5.iter().enumerate() {
+            if i >= self.n_digits {return n_new_digits - 1}
+            match self.digits[i].cmp(p) {
+                core::cmp::Ordering::Equal   => continue,
+                core::cmp::Ordering::Less    => return n_new_digits - 1,
+                core::cmp::Ordering::Greater => return n_new_digits,
+            }
+        }
+        n_new_digits
+    }
+
+    /// Divide by `2^shift` ( `shift <= 60` )
+    fn right_shift(&mut self, shift: usize) {
+        let (mut read, mut write) = (0, 0);
+        let mut n = 0u64;
+        while (n >> shift) == 0 {
+            if read < self.n_digits {
+                n = 10 * n + self.digits[read] as u64;
+                read += 1
+            } else if n == 0 {
+                return
+            } else {
+                while (n >> shift) == 0 {
+                    n *= 10;
+                    read += 1
+                }
+                break
+            }
+        }
+        self.decimal_point -= read as i32 - 1;
+        if self.decimal_point < -Self::DECIMAL_POINT_RANGE {
+            (self.n_digits, self.decimal_point, self.truncated) = (0, 0, false);
+            return
+        }
+        let mask = (1u64 << shift) - 1;
+        while read < self.n_digits {
+            let digit = (n >> shift) as u8;
+            n = 10 * (n & mask) + self.digits[read] as u64;
+            read += 1;
+            self.digits[write] = digit;
+            write += 1
+        }
+        while n > 0 {
+            let digit = (n >> shift) as u8;
+            n = 10 * (n & mask);
+            if write < Self::MAX_DIGITS {
+                self.digits[write] = digit;
+                write += 1
+            } else if digit > 0 {
+                self.truncated = true
+            }
+        }
+        self.n_digits = write;
+        self.trim()
+    }
+
+    /// Simple decimal conversion: shift by powers of two into `[1/2, 1)` and round the mantissa
+    fn into_float<T: Float>(mut self) -> BiasedFp {
+        const MAX_SHIFT: usize = 60;
+        /// `floor(log2(10^n))` for small `n`, shifting by which keeps the decimal point
+        const SHIFTS: [usize; 19] = [0, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53, 56, 59];
+        let shift_of = |n: usize| SHIFTS.get(n).copied().unwrap_or(MAX_SHIFT);
+
+        let (zero, infinity) = (BiasedFp::zero_pow2(0), BiasedFp::zero_pow2(T::INFINITE_POWER));
+        if self.n_digits == 0 || self.decimal_point < -324 {
+            return zero
+        } else if self.decimal_point >= 310 {
+            return infinity
+        }
+
+        let mut exp2 = 0i32;
+        while self.decimal_point > 0 {
+            let shift = shift_of(self.decimal_point as usize);
+            self.right_shift(shift);
+            if self.decimal_point < -Self::DECIMAL_POINT_RANGE {return zero}
+            exp2 += shift as i32
+        }
+        while self.decimal_point <= 0 {
+            let shift = if self.decimal_point == 0 {
+                match self.digits[0] {
+                    5.. => break,
+                    0 | 1 => 2,
+                    _ => 1,
+                }
+            } else {
+                shift_of(-self.decimal_point as usize)
+            };
+            self.left_shift(shift);
+            if self.decimal_point > Self::DECIMAL_POINT_RANGE {return infinity}
+            exp2 -= shift as i32
+        }
+
+        /* `[1/2, 1)` to `[1, 2)` */
+        exp2 -= 1;
+        while T::MINIMUM_EXPONENT + 1 > exp2 {
+            let shift = ((T::MINIMUM_EXPONENT + 1 - exp2) as usize).min(MAX_SHIFT);
+            self.right_shift(shift);
+            exp2 += shift as i32
+        }
+        if exp2 - T::MINIMUM_EXPONENT >= T::INFINITE_POWER {
+            return infinity
+        }
+
+        self.left_shift(T::MANTISSA_EXPLICIT_BITS as usize + 1);
+        let mut mantissa = self.round();
+        if mantissa >= (1 << (T::MANTISSA_EXPLICIT_BITS + 1)) {
+            /* rounded up to the carry bit */
+            self.right_shift(1);
+            exp2 += 1;
+            mantissa = self.round();
+            if exp2 - T::MINIMUM_EXPONENT >= T::INFINITE_POWER {
+                return infinity
+            }
+        }
+        let mut power2 = exp2 - T::MINIMUM_EXPONENT;
+        if mantissa < (1 << T::MANTISSA_EXPLICIT_BITS) {
+            power2 -= 1
+        }
+        mantissa &= (1 << T::MANTISSA_EXPLICIT_BITS) - 1;
+        BiasedFp { f: mantissa, e: power2 }
+    }
+}
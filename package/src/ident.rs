@@ -0,0 +1,55 @@
+use crate::byte_set::ByteSet;
+use crate::xid::is_xid_continue;
+
+/// What characters continue an identifier, deciding where a keyword ends as a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentClass {
+    /// `a-z`, `A-Z`, `0-9` and `_`
+    Ascii,
+    /// bytes in the set
+    Bytes(ByteSet),
+    /// Unicode `XID_Continue` characters in UTF-8
+    UnicodeXid,
+}
+
+impl IdentClass {
+    /// Whether `bytes` starts with an identifier character
+    #[inline] pub fn starts(&self, bytes: &[u8]) -> bool {
+        match self {
+            Self::Ascii      => bytes.first().is_some_and(|b| ByteSet::IDENT_CONTINUE.contains(*b)),
+            Self::Bytes(set) => bytes.first().is_some_and(|b| set.contains(*b)),
+            Self::UnicodeXid => first_char(bytes).is_some_and(is_xid_continue),
+        }
+    }
+    /// Whether `bytes` ends with an identifier character
+    #[inline] pub fn ends(&self, bytes: &[u8]) -> bool {
+        match self {
+            Self::Ascii      => bytes.last().is_some_and(|b| ByteSet::IDENT_CONTINUE.contains(*b)),
+            Self::Bytes(set) => bytes.last().is_some_and(|b| set.contains(*b)),
+            Self::UnicodeXid => last_char(bytes).is_some_and(is_xid_continue),
+        }
+    }
+
+    /// Whether `len` bytes of `bytes` make a whole word, not followed by an identifier character
+    /// unless they end with a non-identifier character like `==`
+    #[inline] pub(crate) fn is_word_end(&self, bytes: &[u8], len: usize) -> bool {
+        let (word, rest) = bytes.split_at(len);
+        !(self.ends(word) && self.starts(rest))
+    }
+}
+
+fn first_char(bytes: &[u8]) -> Option<char> {
+    let len = match bytes.first()? {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _           => return None,
+    };
+    core::str::from_utf8(bytes.get(..len)?).ok()?.chars().next()
+}
+fn last_char(bytes: &[u8]) -> Option<char> {
+    let start = (bytes.len().saturating_sub(4)..bytes.len()).rev()
+        .find(|i| bytes[*i] & 0b1100_0000 != 0b1000_0000)?;
+    core::str::from_utf8(&bytes[start..]).ok()?.chars().next()
+}
@@ -0,0 +1,125 @@
+use crate::pattern::Pattern;
+use crate::ident::IdentClass;
+
+/// Table of keywords or operators mapped to values, built in `const` context like
+/// `const TOKENS: Keywords<Tok, 2> = Keywords::new([("true", Tok::True), ("==", Tok::EqEq)]);`
+///
+/// Matches the longest keyword in one pass over the input. When a keyword is listed
+/// more than once, the first one is taken. By `whole_words`, a keyword matches only as a whole word,
+/// and by `ignore_ascii_case`, ASCII case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keywords<'k, T: Copy, const N: usize> {
+    /// sorted by the keyword, and then by the position in the list
+    entries:     [(&'k [u8], T, usize); N],
+    boundary:    Option<IdentClass>,
+    ignore_case: bool,
+}
+
+impl<'k, T: Copy, const N: usize> Keywords<'k, T, N> {
+    pub const fn new(entries: [(&'k str, T); N]) -> Self {
+        if N == 0 {
+            // SAFETY: an empty array
+            return Self { entries: unsafe {core::mem::zeroed()}, boundary: None, ignore_case: false }
+        }
+        let mut bytes = [(entries[0].0.as_bytes(), entries[0].1); N];
+        let mut i = 1; while i < N {
+            bytes[i] = (entries[i].0.as_bytes(), entries[i].1);
+            i += 1
+        }
+        Self::from_bytes(bytes)
+    }
+    /// `new` with byte-sequence keywords
+    pub const fn from_bytes(entries: [(&'k [u8], T); N]) -> Self {
+        if N == 0 {
+            // SAFETY: an empty array
+            return Self { entries: unsafe {core::mem::zeroed()}, boundary: None, ignore_case: false }
+        }
+        let mut listed = [(entries[0].0, entries[0].1, 0); N];
+        let mut i = 1; while i < N {
+            listed[i] = (entries[i].0, entries[i].1, i);
+            i += 1
+        }
+        Self { entries: listed, boundary: None, ignore_case: false }.sorted()
+    }
+
+    /// Insertion sort of the entries by the keyword, and then by the position in the list
+    const fn sorted(mut self) -> Self {
+        let mut i = 1; while i < N {
+            let mut j = i;
+            while j > 0 && precedes(&self.entries[j], &self.entries[j - 1], self.ignore_case) {
+                let entry = self.entries[j];
+                self.entries[j] = self.entries[j - 1];
+                self.entries[j - 1] = entry;
+                j -= 1
+            }
+            i += 1
+        }
+        self
+    }
+
+    /// Match a keyword only when it's not followed by an identifier character of `ident`
+    /// ( unless it ends with a non-identifier character like `==` ), so that `true` doesn't match `trueish`
+    pub const fn whole_words(mut self, ident: IdentClass) -> Self {
+        self.boundary = Some(ident);
+        self
+    }
+    /// Match a keyword ASCII case-insensitively, so that `select` matches `SELECT` and `Select`
+    pub const fn ignore_ascii_case(mut self) -> Self {
+        self.ignore_case = true;
+        self.sorted()
+    }
+
+    /// Value of `keyword`, or `None` if it's not in the table
+    pub fn get(&self, keyword: impl AsRef<[u8]>) -> Option<T> {
+        let keyword = keyword.as_ref();
+        self.longest_prefix(keyword).and_then(|(len, value)| (len == keyword.len()).then_some(value))
+    }
+
+    /// Length and value of the longest keyword `bytes` starts with, or `None` if none
+    pub fn longest_prefix(&self, bytes: &[u8]) -> Option<(usize, T)> {
+        let mut found = None;
+        let (mut entries, mut depth) = (&self.entries[..], 0);
+        loop {
+            /* every keyword in `entries` starts with `bytes[..depth]`, and exact ones come first */
+            if let Some((keyword, value, _)) = entries.first() {
+                if keyword.len() == depth {
+                    if self.boundary.is_none_or(|ident| ident.is_word_end(bytes, depth)) {
+                        found = Some((depth, *value))
+                    }
+                    entries = &entries[entries.partition_point(|(k, ..)| k.len() == depth)..];
+                }
+            }
+            let Some(b) = bytes.get(depth) else {break};
+            if entries.is_empty() {break}
+
+            let b = fold(*b, self.ignore_case);
+            let start = entries.partition_point(|(k, ..)| fold(k[depth], self.ignore_case) < b);
+            let end   = entries.partition_point(|(k, ..)| fold(k[depth], self.ignore_case) <= b);
+            entries = &entries[start..end];
+            depth += 1
+        }
+        found
+    }
+}
+
+#[inline(always)] const fn fold(b: u8, ignore_case: bool) -> u8 {
+    if ignore_case {b.to_ascii_lowercase()} else {b}
+}
+const fn is_less(a: &[u8], b: &[u8], ignore_case: bool) -> bool {
+    let mut i = 0; while i < a.len() && i < b.len() {
+        let (a, b) = (fold(a[i], ignore_case), fold(b[i], ignore_case));
+        if a != b {return a < b}
+        i += 1
+    }
+    a.len() < b.len()
+}
+/// `a` comes before `b` by the keyword, or by the position in the list for the same keyword
+const fn precedes<T>(a: &(&[u8], T, usize), b: &(&[u8], T, usize), ignore_case: bool) -> bool {
+    is_less(a.0, b.0, ignore_case) || (!is_less(b.0, a.0, ignore_case) && a.2 < b.2)
+}
+
+impl<T: Copy, const N: usize> Pattern for &Keywords<'_, T, N> {
+    #[inline] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.longest_prefix(haystack).map(|(len, _)| len)
+    }
+}
@@ -1,29 +1,141 @@
 #![no_std]
 #![doc(html_root_url = "https://docs.rs/byte_reader")]
 
-pub struct Reader<'r> {
-    buf:  &'r [u8],
-    size: usize,
-    pub index: usize,
-    /// **`location` feature required**\
-    /// Line of current parsing point
-    #[cfg(feature="location")] pub line: usize,
-    /// **`location` feature required**\
-    /// Column of current parsing point
-    #[cfg(feature="location")] pub column: usize,
+#[cfg(feature="alloc")] extern crate alloc;
+
+mod error;
+pub use error::{ParseError, Expected};
+#[cfg(feature="text")] pub use error::{NumberError, NumberErrorKind, StringError, StringErrorKind, UnescapeError};
+
+mod span;
+pub use span::Span;
+
+mod location;
+pub use location::{Location, Locator, NoLocation, LineColumn, Newline, ColumnUnit};
+
+mod line_index;
+pub use line_index::{LineIndex, Indexed};
+
+mod pattern;
+pub use pattern::Pattern;
+
+mod byte_set;
+pub use byte_set::ByteSet;
+
+mod finder;
+pub use finder::Finder;
+
+mod multi_finder;
+pub use multi_finder::MultiFinder;
+
+mod keywords;
+pub use keywords::Keywords;
+
+mod ident;
+pub use ident::IdentClass;
+
+mod xid;
+
+#[cfg(feature="text")] mod number;
+#[cfg(feature="text")] pub use number::{Integer, IntegerLiteral, Decimal};
+
+#[cfg(feature="text")] mod float;
+#[cfg(feature="text")] pub use float::{Float, FloatSyntax};
+#[cfg(feature="text")] mod pow5;
+
+#[cfg(feature="text")] mod escape;
+#[cfg(feature="text")] pub use escape::{Escapes, Escaped, Unescape};
+
+mod scan;
+
+/// Byte-by-byte reader of an input, tracking the location of its parsing point by `L` ( see `Locator` )
+#[derive(Clone)]
+pub struct Reader<'r, L: Locator = NoLocation> {
+    buf:     &'r [u8],
+    size:    usize,
+    index:   usize,
+    base:    usize,
+    locator: L,
+}
+
+/// Snapshot of a `Reader`'s parsing point, created by `checkpoint` and restored by `restore`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint<L: Locator = NoLocation> {
+    /// address of the input, telling the `Reader` it was taken from
+    input:   usize,
+    index:   usize,
+    locator: L,
+}
+impl<L: Locator> Checkpoint<L> {
+    /// Index of the parsing point
+    #[inline(always)] pub const fn index(&self) -> usize {self.index}
+    /// Location of the parsing point, or `None` if not tracked
+    #[inline(always)] pub fn location(&self) -> Option<Location> {self.locator.location()}
 }
 
 impl<'r> Reader<'r> {
     pub const fn new(buf: &'r [u8]) -> Self {
+        Self::with_locator(buf, NoLocation)
+    }
+}
+
+impl<'r> Reader<'r, LineColumn> {
+    /// Create a reader of a fragment of some enclosing document, like a code block in Markdown,
+    /// whose first byte is at `base_index`, line `base_line` and column `base_column` in the document.
+    /// 
+    /// `index`, `line` and `column` ( and so spans and errors ) are reported as positions in the document.
+    /// 
+    /// - Panics if `base_line` or `base_column` is 0
+    pub const fn with_origin(buf: &'r [u8], base_index: usize, base_line: usize, base_column: usize) -> Self {
+        Self::with_locator_at(buf, base_index, LineColumn::new().origin(base_line, base_column))
+    }
+
+    /// Line of current parsing point
+    #[inline(always)] pub const fn line(&self) -> usize {
+        self.locator.line()
+    }
+    /// Column of current parsing point
+    #[inline(always)] pub const fn column(&self) -> usize {
+        self.locator.column()
+    }
+}
+impl<'r> Reader<'r, Indexed<'_>> {
+    /// Line of current parsing point
+    #[inline(always)] pub const fn line(&self) -> usize {
+        self.locator.line()
+    }
+    /// Column of current parsing point
+    #[inline(always)] pub const fn column(&self) -> usize {
+        self.locator.column()
+    }
+}
+
+impl<'r, L: Locator> Reader<'r, L> {
+    /// Create a reader tracking its location by `locator` like `LineColumn::new()`
+    pub const fn with_locator(buf: &'r [u8], locator: L) -> Self {
+        Self::with_locator_at(buf, 0, locator)
+    }
+    /// Create a reader of a fragment whose first byte is at `base_index` in some enclosing document,
+    /// tracking its location by `locator` like `LineColumn::new().origin(line, column)`
+    pub const fn with_locator_at(buf: &'r [u8], base_index: usize, locator: L) -> Self {
         Self {
             buf,
             size:  buf.len(),
             index: 0,
-            #[cfg(feature="location")] line:   1,
-            #[cfg(feature="location")] column: 1,
+            base:  base_index,
+            locator,
         }
     }
 
+    /// Index of current parsing point ( counted from the base index if the reader has it )
+    #[inline(always)] pub const fn index(&self) -> usize {
+        self.base + self.index
+    }
+    /// Location of current parsing point, or `None` if not tracked
+    #[inline(always)] pub fn location(&self) -> Option<Location> {
+        self.locator.location()
+    }
+
     #[inline(always)] pub fn remaining(&self) -> &[u8] {
         unsafe {self.buf.get_unchecked(self.index..)}
     }
@@ -32,92 +144,186 @@ impl<'r> Reader<'r> {
     }
 
     #[inline] fn advance_unchecked_by(&mut self, n: usize) {
-        #[cfg(feature="location")] {
-            let mut line   = self.line;
-            let mut column = self.column;
-            for b in unsafe {self.buf.get_unchecked(self.index..(self.index + n))} {
-                if &b'\n' != b {
-                    column += 1
-                } else {
-                    line += 1; column = 1
-                }
-            }
-            self.line   = line;
-            self.column = column;
-        }
+        self.locator.advance(self.buf, self.index, self.index + n);
         self.index += n;
     }
     #[inline] fn unwind_unchecked_by(&mut self, n: usize) {
-        #[cfg(feature="location")] unsafe {
-            let mut line   = self.line;
-            let mut column = self.column;
-            for i in 1..=n {let here = self.index - i;
-                if self.get_unchecked(here) != &b'\n' {
-                    column -= 1
-                } else {
-                    line -= 1; column = 'c: {
-                        for j in 1..=here {
-                            if self.get_unchecked(here - j) == &b'\n' {break 'c j}
-                        }; here + 1
-                    }
-                }
-            }
-            self.line   = line;
-            self.column = column;
-        }
+        self.locator.unwind(self.buf, self.index, self.index - n);
         self.index -= n;
     }
+    /// Locator state at `index` ( <= `self.size` ), calculated from the current parsing point
+    #[inline] fn locator_at(&self, index: usize) -> L {
+        let mut locator = self.locator;
+        if index >= self.index {
+            locator.advance(self.buf, self.index, index)
+        } else {
+            locator.unwind(self.buf, self.index, index)
+        }
+        locator
+    }
     /// Advance by `max` bytes (or, if remaining bytes is shorter than `max`, read all remaining bytes)
     #[inline(always)] pub fn advance_by(&mut self, max: usize) {
         self.advance_unchecked_by(max.min(self.size - self.index))
     }
     /// Unwind the parsing point by `max` bytes (or, if already-read bytes is shorter than `max`, rewind all)
     /// 
-    /// When `"location"` feature is activated, this may be *less performant* for some extensive input
+    /// See [`LineColumn`] for the cost of moving backward with it
     pub fn unwind_by(&mut self, max: usize) {
         self.unwind_unchecked_by(max.min(self.index))
     }
 
-    /// Skip next byte while `condition` holds on it
-    #[inline] pub fn skip_while(&mut self, condition: impl Fn(&u8)->bool) {
-        let mut by = 0; for b in self.remaining() {
-            if condition(b) {by += 1} else {break}
+    /// Move the parsing point to `offset` (or, if `offset` is beyond the input, to the end of input),
+    /// keeping line and column consistent
+    /// 
+    /// `offset` is an index like `index()` returns, so it's counted from the base index if the reader has it
+    /// 
+    /// See [`LineColumn`] for the cost of moving backward with it
+    #[inline] pub fn seek(&mut self, offset: usize) {
+        let offset = offset.saturating_sub(self.base).min(self.size);
+        self.locator = self.locator_at(offset);
+        self.index   = offset;
+    }
+    /// Move the parsing point by `delta` bytes forward (positive) or backward (negative),
+    /// stopping at the start or end of the input
+    #[inline] pub fn seek_relative(&mut self, delta: isize) {
+        if delta >= 0 {
+            self.advance_by(delta as usize)
+        } else {
+            self.unwind_by(delta.unsigned_abs())
+        }
+    }
+
+    /// Take a snapshot of the current parsing point
+    #[inline(always)] pub fn checkpoint(&self) -> Checkpoint<L> {
+        Checkpoint {
+            input:   self.buf.as_ptr() as usize,
+            index:   self.index(),
+            locator: self.locator,
+        }
+    }
+    /// Jump back (or forward) to the parsing point of `checkpoint` in constant time
+    /// 
+    /// - Panics if `checkpoint` was taken from a `Reader` of another input
+    #[inline] pub fn restore(&mut self, checkpoint: Checkpoint<L>) {
+        let index = checkpoint.index.wrapping_sub(self.base);
+        assert!(checkpoint.input == self.buf.as_ptr() as usize && index <= self.size, "`checkpoint` was taken from another input");
+        self.index   = index;
+        self.locator = checkpoint.locator;
+    }
+
+    /// Span between `checkpoint` and the current parsing point
+    #[inline] pub fn span_since(&self, checkpoint: Checkpoint<L>) -> Span {
+        let here = self.checkpoint();
+        let (start, end) = if checkpoint.index <= here.index {(checkpoint, here)} else {(here, checkpoint)};
+        Span {
+            start:          start.index,
+            end:            end.index,
+            start_location: start.location(),
+            end_location:   end.location(),
         }
+    }
+    /// Span of `bytes` if it's a sub-slice of the input ( like what `read_*` returned ), or `None` if not
+    /// 
+    /// See [`LineColumn`] for the cost of moving backward with it
+    pub fn span_of(&self, bytes: &[u8]) -> Option<Span> {
+        let start = (bytes.as_ptr() as usize).checked_sub(self.buf.as_ptr() as usize)?;
+        let end   = start + bytes.len();
+        if end > self.size {return None}
+
+        Some(Span {
+            start:          self.base + start,
+            end:            self.base + end,
+            start_location: self.locator_at(start).location(),
+            end_location:   self.locator_at(end).location(),
+        })
+    }
+
+    /// Run `parse` on the reader, and if it returns `None`, roll the parsing point back as if nothing was consumed
+    #[inline] pub fn attempt<T>(&mut self, parse: impl FnOnce(&mut Self)->Option<T>) -> Option<T> {
+        let checkpoint = self.checkpoint();
+        let result = parse(self);
+        if result.is_none() {self.restore(checkpoint)}
+        result
+    }
+    /// Run `parse` on the reader, and if it returns `Err`, roll the parsing point back as if nothing was consumed
+    #[inline] pub fn attempt_result<T, E>(&mut self, parse: impl FnOnce(&mut Self)->Result<T, E>) -> Result<T, E> {
+        let checkpoint = self.checkpoint();
+        let result = parse(self);
+        if result.is_err() {self.restore(checkpoint)}
+        result
+    }
+
+    /// Run `parse` on the reader, and return the bytes consumed by it as a contiguous slice of the input.
+    /// 
+    /// Or, if `parse` returns `None`, roll the parsing point back like `attempt` and return `None`.
+    #[inline] pub fn recognize<T>(&mut self, parse: impl FnOnce(&mut Self)->Option<T>) -> Option<&'r [u8]> {
+        let start = self.index;
+        self.attempt(parse)?;
+        Some(unsafe {self.buf.get_unchecked(start..self.index)})
+    }
+    /// `recognize` returning also the span of the bytes
+    #[inline] pub fn recognize_spanned<T>(&mut self, parse: impl FnOnce(&mut Self)->Option<T>) -> Option<(&'r [u8], Span)> {
+        let start = self.checkpoint();
+        let bytes = self.recognize(parse)?;
+        Some((bytes, self.span_since(start)))
+    }
+
+    /// Skip while the remaining bytes start with `pattern`, like a byte satisfying `|b: &u8| b.is_ascii_digit()`
+    /// 
+    /// A byte, or a `ByteSet` of up to 4 ASCII ranges like the predefined ones, is scanned 8 or 16 bytes at a time.
+    #[inline] pub fn skip_while(&mut self, mut pattern: impl Pattern) {
+        let by = pattern.match_prefix_repeated(self.remaining()).min(self.size - self.index);
         self.advance_unchecked_by(by)
     }
-    /// `skip_while(u8::is_ascii_whitespace)`
+    /// `skip_while(ByteSet::WHITESPACE)`
     #[inline] pub fn skip_whitespace(&mut self) {
-        self.skip_while(u8::is_ascii_whitespace)
+        let by = scan::whitespace_len(self.remaining());
+        self.advance_unchecked_by(by)
+    }
+    /// Read while the remaining bytes start with `pattern`
+    #[inline] pub fn read_while(&mut self, pattern: impl Pattern) -> &'r [u8] {
+        let start = self.index;
+        self.skip_while(pattern);
+        unsafe {self.buf.get_unchecked(start..self.index)}
     }
-    /// Read next byte while the condition holds on it
-    #[inline] pub fn read_while(&mut self, condition: impl Fn(&u8)->bool) -> &'r [u8] {
+    /// `read_while` returning also the span of the bytes
+    #[inline] pub fn read_while_spanned(&mut self, pattern: impl Pattern) -> (&'r [u8], Span) {
+        let start = self.checkpoint();
+        let bytes = self.read_while(pattern);
+        (bytes, self.span_since(start))
+    }
+    /// Read through until the `pattern` comes in front of reader (or, if not found, read all remaining bytes).
+    /// 
+    /// Byte sequences are searched by the Two-Way algorithm in O(n + m); to reuse the precomputation, pass a `Finder`.
+    #[inline] pub fn read_until(&mut self, mut pattern: impl Pattern) -> &'r [u8] {
         let start = self.index;
-        self.skip_while(condition);
+        let rest = self.size - self.index;
+        let by = pattern.find(self.remaining()).map_or(rest, |(at, _)| at.min(rest));
+        self.advance_unchecked_by(by);
         unsafe {self.buf.get_unchecked(start..self.index)}
     }
-    /// Read through until the `pattern` comes in front of reader.
-    #[inline] pub fn read_until(&mut self, pattern: impl AsRef<[u8]>) -> &'r [u8] {
-        let pattern = pattern.as_ref();
 
+    /// Read through until any of the patterns of `finder` comes in front of reader, and return
+    /// the bytes with the index of the (matched) pattern ( or, if none is found, all remaining bytes with `None` ).
+    #[inline] pub fn read_until_any<const N: usize, const S: usize>(&mut self, finder: &MultiFinder<'_, N, S>) -> (&'r [u8], Option<usize>) {
         let start = self.index;
-        let pat_len = pattern.len();
-
-        let mut i = self.index;
-        while i+pat_len <= self.size {
-            unsafe {
-                if self.buf.get_unchecked(i..i+pat_len) == pattern {
-                    self.advance_unchecked_by(i - self.index);
-                    return self.buf.get_unchecked(start..self.index)
-                }
-            }
-            i += 1
-        }
+        let (by, pattern) = match finder.find_pattern(self.remaining()) {
+            Some((at, pattern)) => (at, Some(pattern)),
+            None                => (self.size - self.index, None),
+        };
+        self.advance_unchecked_by(by);
+        (unsafe {self.buf.get_unchecked(start..self.index)}, pattern)
+    }
 
-        self.advance_unchecked_by(self.size - self.index);
-        unsafe {self.buf.get_unchecked(start..self.size)}
+    /// `read_until` returning also the span of the bytes
+    #[inline] pub fn read_until_spanned(&mut self, pattern: impl Pattern) -> (&'r [u8], Span) {
+        let start = self.checkpoint();
+        let bytes = self.read_until(pattern);
+        (bytes, self.span_since(start))
     }
 
     /// Read next byte, or return None if the remaining bytes is empty
+    #[allow(clippy::should_implement_trait)]
     #[inline] pub fn next(&mut self) -> Option<u8> {
         let here = self.index;
         self.advance_by(1);
@@ -125,7 +331,7 @@ impl<'r> Reader<'r> {
     }
     /// Read next byte if the condition holds on it
     #[inline] pub fn next_if(&mut self, condition: impl Fn(&u8)->bool) -> Option<u8> {
-        let value = self.peek()?.clone();
+        let value = *self.peek()?;
         condition(&value).then(|| {self.advance_unchecked_by(1); value})
     }
 
@@ -142,48 +348,102 @@ impl<'r> Reader<'r> {
         (self.size - self.index > 2).then(|| unsafe {self.get_unchecked(self.index + 2)})
     }
 
+    /// Whether the remaining bytes start with `pattern` (without consuming)
+    #[inline] pub fn starts_with(&self, mut pattern: impl Pattern) -> bool {
+        pattern.match_prefix(self.remaining()).is_some()
+    }
+
     /// Read `token` if the remaining bytes start with it
-    #[inline(always)] pub fn consume(&mut self, token: impl AsRef<[u8]>) -> Option<()> {
-        let token = token.as_ref();
-        let n = token.len();
-        (self.size - self.index >= n && unsafe {
-            self.buf.get_unchecked(self.index..(self.index + n))
-        } == token).then(|| self.advance_unchecked_by(n))
+    #[inline(always)] pub fn consume(&mut self, mut token: impl Pattern) -> Option<()> {
+        let n = token.match_prefix(self.remaining())?;
+        self.advance_unchecked_by(n.min(self.size - self.index));
+        Some(())
     }
     /// Read the first token in `tokens` that matches the start with the remaining bytes, and returns the index of the (matched) token, or `None` if none matches
-    #[inline(always)] pub fn consume_oneof<const N: usize>(&mut self, tokens: [impl AsRef<[u8]>; N]) -> Option<usize> {
-        for i in 0..tokens.len() {
-            let token = tokens[i].as_ref();
-            if self.remaining().starts_with(token) {
-                self.advance_unchecked_by(token.len());
+    #[inline(always)] pub fn consume_oneof<const N: usize>(&mut self, tokens: [impl Pattern; N]) -> Option<usize> {
+        for (i, mut token) in tokens.into_iter().enumerate() {
+            if let Some(n) = token.match_prefix(self.remaining()) {
+                self.advance_unchecked_by(n.min(self.size - self.index));
                 return Some(i)
             }
         }; None
     }
+
+    /// Read `token` if the remaining bytes start with it ASCII case-insensitively, and return the bytes as they are in the input
+    #[inline] pub fn consume_ignore_ascii_case(&mut self, token: impl AsRef<[u8]>) -> Option<&'r [u8]> {
+        let token = token.as_ref();
+        let bytes = self.buf.get(self.index..self.index + token.len())?;
+        if !bytes.eq_ignore_ascii_case(token) {return None}
+        self.advance_unchecked_by(token.len());
+        Some(bytes)
+    }
+    /// `read_until` comparing `needle` ASCII case-insensitively ( to reuse the precomputation, pass `Finder::new_ignore_ascii_case` to `read_until` )
+    #[inline] pub fn read_until_ignore_ascii_case(&mut self, needle: impl AsRef<[u8]>) -> &'r [u8] {
+        self.read_until(Finder::new_ignore_ascii_case(needle.as_ref()))
+    }
+
+    /// Read `keyword` if the remaining bytes start with it as a whole word, not followed by
+    /// `a-z`, `A-Z`, `0-9` or `_` ( unless it ends with a non-identifier character like `==` )
+    #[inline] pub fn consume_keyword(&mut self, keyword: impl AsRef<[u8]>) -> Option<()> {
+        self.consume_keyword_by(keyword, IdentClass::Ascii)
+    }
+    /// `consume_keyword` with the identifier characters of `ident`
+    #[inline] pub fn consume_keyword_by(&mut self, keyword: impl AsRef<[u8]>, ident: IdentClass) -> Option<()> {
+        let keyword = keyword.as_ref();
+        (self.remaining().starts_with(keyword) && ident.is_word_end(self.remaining(), keyword.len()))
+            .then(|| self.advance_unchecked_by(keyword.len()))
+    }
+
+    /// Read the longest keyword in `keywords` that the remaining bytes start with, and return its value
+    /// ( or `None` if none matches )
+    #[inline] pub fn read_keyword<T: Copy, const N: usize>(&mut self, keywords: &Keywords<'_, T, N>) -> Option<T> {
+        let (len, value) = keywords.longest_prefix(self.remaining())?;
+        self.advance_unchecked_by(len);
+        Some(value)
+    }
+
+    /// Create a `ParseError` expecting `expected` at the current parsing point
+    #[inline] pub fn error<'t>(&self, expected: Expected<'t>) -> ParseError<'t> {
+        ParseError {
+            expected,
+            found: self.peek().copied(),
+            index:    self.index(),
+            location: self.location(),
+        }
+    }
+    /// `consume` returning `ParseError` if the remaining bytes don't start with `token`
+    #[inline] pub fn expect<'t>(&mut self, token: &'t (impl AsRef<[u8]> + ?Sized)) -> Result<(), ParseError<'t>> {
+        let token = token.as_ref();
+        self.consume(token).ok_or_else(|| self.error(Expected::Token(token)))
+    }
+    /// `consume_oneof` returning `ParseError` if none of `tokens` matches
+    #[inline] pub fn expect_oneof<'t, const N: usize>(&mut self, tokens: &'t [&'t str; N]) -> Result<usize, ParseError<'t>> {
+        self.consume_oneof(*tokens).ok_or_else(|| self.error(Expected::OneOf(tokens)))
+    }
 }
 
 #[cfg(feature="text")]
-impl<'r> Reader<'r> {
+impl<'r, L: Locator> Reader<'r, L> {
     /// **`text` feature required**\
     /// Read a `camelCase` word like `helloWorld`, `userID`, ... as `&str` if found
     #[inline] pub fn read_camel(&mut self) -> Option<&'r str> {
-        let ident_bytes = self.read_while(|b| matches!(b, b'a'..=b'z' | b'A'..=b'Z'));
+        let ident_bytes = self.read_while(ByteSet::ALPHABETIC);
         // SAFETY: `ident_bytes` is consists of `b'a'..=b'z' | b'A'..=b'Z'`
-        (ident_bytes.len() > 0).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
+        (!ident_bytes.is_empty()).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
     }
     /// **`text` feature required**\
     /// Read a `snake_case` word like `hello_world`, `user_id`, ... as `&str` if found
     #[inline] pub fn read_snake(&mut self) -> Option<&'r str> {
-        let ident_bytes = self.read_while(|b| matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'_'));
+        let ident_bytes = self.read_while(ByteSet::IDENT_START);
         // SAFETY: `ident_bytes` is consists of `b'a'..=b'z' | b'A'..=b'Z' | b'_'`
-        (ident_bytes.len() > 0).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
+        (!ident_bytes.is_empty()).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
     }
     /// **`text` feature required**\
     /// Read a `kebeb-case` word like `hello-world`, `Content-Type`, ... as `&str` if found
     #[inline] pub fn read_kebab(&mut self) -> Option<&'r str> {
-        let ident_bytes = self.read_while(|b| matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'-'));
+        let ident_bytes = self.read_while(ByteSet::ALPHABETIC.bytes(b"-"));
         // SAFETY: `ident_bytes` is consists of `b'a'..=b'z' | b'A'..=b'Z' | b'-'`
-        (ident_bytes.len() > 0).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
+        (!ident_bytes.is_empty()).then(|| unsafe {core::str::from_utf8_unchecked(ident_bytes)})
     }
 
     /// **`text` feature required**\
@@ -193,7 +453,7 @@ impl<'r> Reader<'r> {
     #[inline] pub fn read_quoted_by(&mut self, left: u8, right: u8) -> Option<&'r [u8]> {
         if self.peek()? != &left {return None}
         let content_len = self.remaining()[1..].iter().take_while(|b| b != &&right).count();
-        let eoq /* end of quotation */ = 0 + content_len + 1;
+        let eoq /* end of quotation */ = content_len + 1;
         if self.remaining().get(eoq)? != &right {return None}
 
         self.advance_unchecked_by(eoq + 1);
@@ -202,27 +462,82 @@ impl<'r> Reader<'r> {
         )})
     }
 
+    /// **`text` feature required**\
+    /// `read_camel` returning also the span of the word
+    #[inline] pub fn read_camel_spanned(&mut self) -> Option<(&'r str, Span)> {
+        let start = self.checkpoint();
+        self.read_camel().map(|word| (word, self.span_since(start)))
+    }
+    /// **`text` feature required**\
+    /// `read_snake` returning also the span of the word
+    #[inline] pub fn read_snake_spanned(&mut self) -> Option<(&'r str, Span)> {
+        let start = self.checkpoint();
+        self.read_snake().map(|word| (word, self.span_since(start)))
+    }
+    /// **`text` feature required**\
+    /// `read_kebab` returning also the span of the word
+    #[inline] pub fn read_kebab_spanned(&mut self) -> Option<(&'r str, Span)> {
+        let start = self.checkpoint();
+        self.read_kebab().map(|word| (word, self.span_since(start)))
+    }
+    /// **`text` feature required**\
+    /// `read_quoted_by` returning also the span of the enclosed bytes ( not including `left` and `right` )
+    #[inline] pub fn read_quoted_by_spanned(&mut self, left: u8, right: u8) -> Option<(&'r [u8], Span)> {
+        let start = self.checkpoint();
+        let bytes = self.read_quoted_by(left, right)?;
+        let mut span = self.span_since(start);
+        span.start += 1;
+        span.end   -= 1;
+        span.start_location = self.locator_at(span.start - self.base).location();
+        span.end_location   = self.locator_at(span.end - self.base).location();
+        Some((bytes, span))
+    }
+
     /// **`text` feature required**\
     /// Read an unsigned integer literal like `42`, `123` as `usize` if found
     /// 
-    /// - Panics if the integer is larger than `usize::MAX`
+    /// Or, returns `None` without reading anything if the integer is larger than `usize::MAX`
+    /// ( see `read_integer` for the reason )
     #[inline] pub fn read_uint(&mut self) -> Option<usize> {
-        let digits = self.read_while(|b| b.is_ascii_digit());
-        (digits.len() > 0).then(|| digits.into_iter().fold(0, |uint, d| uint*10 + (*d-b'0') as usize))
+        self.read_integer().ok()
     }
     /// **`text` feature required**\
     /// Read an integer literal like `42`, `-1111` as `isize` if found
     /// 
-    /// - Panics if not `isize::MIN` <= {the integer} <= `isize::MAX`
+    /// Or, returns `None` without reading anything if not `isize::MIN` <= {the integer} <= `isize::MAX`
+    /// ( see `read_integer` for the reason )
     #[inline] pub fn read_int(&mut self) -> Option<isize> {
-        if self.peek()? != &b'-' {
-            self.read_uint().map(|u| u as isize)
-        } else {
-            let (abs, n_digits) = self.remaining()[1..].iter()
-                .map_while(|b| b.is_ascii_digit().then(|| *b - b'0'))
-                .fold((0, 0), |(abs, n), d| (abs*10+d as isize, n+1));
-            (n_digits > 0).then(|| {
-                self.advance_unchecked_by(1/*'-'*/ + n_digits); -abs})
-        }
+        self.read_integer().ok()
+    }
+
+    /// **`text` feature required**\
+    /// `read_camel` returning `ParseError` if not found
+    #[inline] pub fn expect_camel(&mut self) -> Result<&'r str, ParseError<'static>> {
+        self.read_camel().ok_or_else(|| self.error(Expected::Description("camelCase word")))
+    }
+    /// **`text` feature required**\
+    /// `read_snake` returning `ParseError` if not found
+    #[inline] pub fn expect_snake(&mut self) -> Result<&'r str, ParseError<'static>> {
+        self.read_snake().ok_or_else(|| self.error(Expected::Description("snake_case word")))
+    }
+    /// **`text` feature required**\
+    /// `read_kebab` returning `ParseError` if not found
+    #[inline] pub fn expect_kebab(&mut self) -> Result<&'r str, ParseError<'static>> {
+        self.read_kebab().ok_or_else(|| self.error(Expected::Description("kebab-case word")))
+    }
+    /// **`text` feature required**\
+    /// `read_quoted_by` returning `ParseError` if not found
+    #[inline] pub fn expect_quoted_by(&mut self, left: u8, right: u8) -> Result<&'r [u8], ParseError<'static>> {
+        self.read_quoted_by(left, right).ok_or_else(|| self.error(Expected::Description("quoted bytes")))
+    }
+    /// **`text` feature required**\
+    /// `read_uint` returning `ParseError` if not found
+    #[inline] pub fn expect_uint(&mut self) -> Result<usize, ParseError<'static>> {
+        self.read_uint().ok_or_else(|| self.error(Expected::Description("unsigned integer")))
+    }
+    /// **`text` feature required**\
+    /// `read_int` returning `ParseError` if not found
+    #[inline] pub fn expect_int(&mut self) -> Result<isize, ParseError<'static>> {
+        self.read_int().ok_or_else(|| self.error(Expected::Description("integer")))
     }
 }
@@ -0,0 +1,96 @@
+use crate::location::{Location, Locator, sealed};
+use crate::scan;
+
+/// Table of the start indices of all lines in an input, resolving any index to line and column by binary search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineIndex<'i> {
+    starts: &'i [usize],
+    size:   usize,
+}
+
+impl<'i> LineIndex<'i> {
+    /// Number of lines in `buf`, that is the number of slots `build` needs
+    #[inline] pub fn count_lines(buf: &[u8]) -> usize {
+        1 + scan::count_byte(b'\n', buf)
+    }
+
+    /// Build the table of `buf` into `storage`.
+    ///
+    /// Or, returns `None` if `storage` is shorter than `count_lines(buf)`.
+    pub fn build(buf: &[u8], storage: &'i mut [usize]) -> Option<Self> {
+        let (mut n_lines, mut start) = (1, 0);
+        *storage.first_mut()? = 0;
+        while let Some(nl) = scan::find_byte(b'\n', &buf[start..]) {
+            start += nl + 1;
+            *storage.get_mut(n_lines)? = start;
+            n_lines += 1
+        }
+        Some(Self { starts: &storage[..n_lines], size: buf.len() })
+    }
+
+    /// Number of lines in the input
+    #[inline(always)] pub fn n_lines(&self) -> usize {
+        self.starts.len()
+    }
+    /// Index where the `line` (1-origin) starts, or `None` if the input doesn't have such a line
+    #[inline] pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.starts.get(line.checked_sub(1)?).copied()
+    }
+
+    /// Resolve `index` into its location in O(log n)
+    ///
+    /// - Panics if `index` is beyond the end of the input
+    #[inline] pub fn locate(&self, index: usize) -> Location {
+        assert!(index <= self.size, "`index` is out of the input");
+        let line = self.starts.partition_point(|start| *start <= index);
+        Location { line, column: index - self.starts[line - 1] + 1 }
+    }
+}
+
+/// `Locator` resolving line and column by a `LineIndex` in O(log n) on every move
+///
+/// Like the default `LineColumn`, only `\n` is a newline and every byte is one column.
+///
+/// - `Reader` panics when it moves if the `LineIndex` is built from a shorter input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indexed<'i> {
+    index:    &'i LineIndex<'i>,
+    location: Location,
+    origin:   Location,
+}
+impl<'i> Indexed<'i> {
+    pub const fn new(index: &'i LineIndex<'i>) -> Self {
+        let origin = Location { line: 1, column: 1 };
+        Self { index, location: origin, origin }
+    }
+    /// Start at `line` and `column`, the same as `LineColumn::origin`
+    pub const fn origin(mut self, line: usize, column: usize) -> Self {
+        assert!(line >= 1 && column >= 1, "`line` and `column` are 1-origin");
+        self.origin   = Location { line, column };
+        self.location = self.origin;
+        self
+    }
+    #[inline(always)] pub const fn line(&self) -> usize {self.location.line}
+    #[inline(always)] pub const fn column(&self) -> usize {self.location.column}
+
+    /// Location of `index` shifted by the origin
+    #[inline] fn locate(&self, index: usize) -> Location {
+        let Location { line, column } = self.index.locate(index);
+        Location {
+            line:   self.origin.line - 1 + line,
+            column: if line == 1 {self.origin.column - 1 + column} else {column},
+        }
+    }
+}
+impl sealed::Sealed for Indexed<'_> {}
+impl Locator for Indexed<'_> {
+    #[inline] fn advance(&mut self, _: &[u8], _: usize, to: usize) {
+        self.location = self.locate(to)
+    }
+    #[inline] fn unwind(&mut self, _: &[u8], _: usize, to: usize) {
+        self.location = self.locate(to)
+    }
+    #[inline(always)] fn location(&self) -> Option<Location> {
+        Some(self.location)
+    }
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+use crate::scan;
+
+pub(crate) mod sealed {pub trait Sealed {}}
+
+/// Line and column (1-origin) of a point in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line:   usize,
+    pub column: usize,
+}
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Strategy of tracking the location of a `Reader`'s parsing point, chosen per reader
+///
+/// - `NoLocation` : tracks nothing ( default, zero cost )
+/// - `LineColumn` : tracks line and column incrementally
+/// - `Indexed`    : resolves line and column by a `LineIndex` in O(log n)
+///
+/// This trait is sealed, so `Reader` only takes these strategies.
+pub trait Locator: Copy + sealed::Sealed {
+    /// Update the state for the parsing point moving forward from `from` to `to` ( `from` <= `to` <= `buf.len()` )
+    fn advance(&mut self, buf: &[u8], from: usize, to: usize);
+    /// Update the state for the parsing point moving backward from `from` to `to` ( `to` <= `from` <= `buf.len()` )
+    fn unwind(&mut self, buf: &[u8], from: usize, to: usize);
+    /// Location of the parsing point, or `None` if not tracked
+    fn location(&self) -> Option<Location>;
+}
+
+/// `Locator` tracking nothing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoLocation;
+impl sealed::Sealed for NoLocation {}
+impl Locator for NoLocation {
+    #[inline(always)] fn advance(&mut self, _: &[u8], _: usize, _: usize) {}
+    #[inline(always)] fn unwind(&mut self, _: &[u8], _: usize, _: usize) {}
+    #[inline(always)] fn location(&self) -> Option<Location> {None}
+}
+
+/// Set of byte sequences counted as newline by `LineColumn`, combinable by `|`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Newline(u8);
+impl Newline {
+    /// `\n`
+    pub const LF:      Self = Self(0b0001);
+    /// `\r\n` ( as one newline )
+    pub const CRLF:    Self = Self(0b0010);
+    /// `\r`
+    pub const CR:      Self = Self(0b0100);
+    /// Unicode LINE SEPARATOR ( U+2028 ) and PARAGRAPH SEPARATOR ( U+2029 ) in UTF-8
+    pub const UNICODE: Self = Self(0b1000);
+    /// `\n`, `\r\n` and `\r`
+    pub const ANY:     Self = Self(0b0111);
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl core::ops::BitOr for Newline {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// What `LineColumn` counts as one column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// a byte
+    Byte,
+    /// a UTF-8 scalar value
+    Char,
+    /// a UTF-16 code unit, like LSP's default `PositionEncodingKind`
+    Utf16,
+}
+
+/// `Locator` tracking line and column incrementally
+///
+/// By default, only `\n` is a newline and every byte is one column. This is configurable by
+/// `newline`, `tab_width` and `column_unit`. The starting location is set by `origin`.
+///
+/// Moving backward ( `unwind_by`, `seek`, `span_of` ) may be *less performant* for some extensive input,
+/// as it rescans the input back to the start of line ( consider `Indexed` locator )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineColumn {
+    line:      usize,
+    column:    usize,
+    newline:   Newline,
+    tab_width: usize,
+    unit:      ColumnUnit,
+    /// column where the first line starts
+    origin_column: usize,
+}
+impl LineColumn {
+    pub const fn new() -> Self {
+        Self { line: 1, column: 1, newline: Newline::LF, tab_width: 1, unit: ColumnUnit::Byte, origin_column: 1 }
+    }
+    /// Start at `line` and `column` instead of line 1, column 1, like for a fragment of some enclosing document.
+    /// Only the first line starts at `column`; lines after a newline start at column 1.
+    ///
+    /// - Panics if `line` or `column` is 0
+    pub const fn origin(mut self, line: usize, column: usize) -> Self {
+        assert!(line >= 1 && column >= 1, "`line` and `column` are 1-origin");
+        self.line          = line;
+        self.column        = column;
+        self.origin_column = column;
+        self
+    }
+    /// Count `newline` ( like `Newline::CRLF | Newline::CR` ) as newline, instead of only `\n`
+    pub const fn newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+    /// Move the column to the next tab stop of `tab_width` columns at `\t`, instead of one column
+    pub const fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = if tab_width == 0 {1} else {tab_width};
+        self
+    }
+    /// Count columns by `unit`, instead of bytes
+    pub const fn column_unit(mut self, unit: ColumnUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    #[inline(always)] pub const fn line(&self) -> usize {self.line}
+    #[inline(always)] pub const fn column(&self) -> usize {self.column}
+
+    #[inline(always)] fn is_default(&self) -> bool {
+        matches!(self.newline, Newline::LF) && self.tab_width == 1 && matches!(self.unit, ColumnUnit::Byte)
+    }
+    /// Whether a newline ends at `buf[i]`
+    #[inline] fn breaks_at(&self, buf: &[u8], i: usize) -> bool {
+        match buf[i] {
+            b'\n' => self.newline.contains(Newline::LF) || (
+                self.newline.contains(Newline::CRLF) && i > 0 && buf[i - 1] == b'\r'
+            ),
+            b'\r' => self.newline.contains(Newline::CR) && !(
+                self.newline.contains(Newline::CRLF) && buf.get(i + 1) == Some(&b'\n')
+            ),
+            0xA8 | 0xA9 => self.newline.contains(Newline::UNICODE) && (
+                i >= 2 && buf[i - 2] == 0xE2 && buf[i - 1] == 0x80
+            ),
+            _ => false,
+        }
+    }
+    /// Column after a non-newline byte `b` at `column`
+    #[inline] fn next_column(&self, column: usize, b: u8) -> usize {
+        if b == b'\t' {
+            return ((column - 1) / self.tab_width + 1) * self.tab_width + 1
+        }
+        let is_leading = b & 0b1100_0000 != 0b1000_0000;
+        match self.unit {
+            ColumnUnit::Byte  => column + 1,
+            ColumnUnit::Char  => column + is_leading as usize,
+            ColumnUnit::Utf16 => column + is_leading as usize + (b >= 0xF0) as usize,
+        }
+    }
+}
+impl Default for LineColumn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl sealed::Sealed for LineColumn {}
+impl Locator for LineColumn {
+    #[inline] fn advance(&mut self, buf: &[u8], from: usize, to: usize) {
+        let (mut line, mut column) = (self.line, self.column);
+        if self.is_default() {
+            let bytes = &buf[from..to];
+            match scan::rfind_byte(b'\n', bytes) {
+                None     => column += bytes.len(),
+                Some(nl) => {
+                    line  += 1 + scan::count_byte(b'\n', &bytes[..nl]);
+                    column = bytes.len() - nl
+                }
+            }
+        } else {
+            for i in from..to {
+                if self.breaks_at(buf, i) {
+                    line += 1; column = 1
+                } else {
+                    column = self.next_column(column, buf[i])
+                }
+            }
+        }
+        (self.line, self.column) = (line, column)
+    }
+    fn unwind(&mut self, buf: &[u8], from: usize, to: usize) {
+        if self.is_default() {
+            let crossed = scan::count_byte(b'\n', &buf[to..from]);
+            if crossed == 0 {
+                self.column -= from - to
+            } else {
+                self.line  -= crossed;
+                self.column = match scan::rfind_byte(b'\n', &buf[..to]) {
+                    Some(nl) => to - nl,
+                    None     => self.origin_column + to,
+                }
+            }
+        } else {
+            let crossed    = (to..from).filter(|i| self.breaks_at(buf, *i)).count();
+            let (line_start, start_column) = match (0..to).rev().find(|i| self.breaks_at(buf, *i)) {
+                Some(nl) => (nl + 1, 1),
+                None     => (0, self.origin_column),
+            };
+            self.line  -= crossed;
+            self.column = buf[line_start..to].iter()
+                .fold(start_column, |column, b| self.next_column(column, *b))
+        }
+    }
+    #[inline(always)] fn location(&self) -> Option<Location> {
+        Some(Location { line: self.line, column: self.column })
+    }
+}
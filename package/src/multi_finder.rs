@@ -0,0 +1,165 @@
+use crate::byte_set::ByteSet;
+use crate::pattern::Pattern;
+
+const NONE: u16 = u16::MAX;
+
+/// Precompiled searcher for any of `N` patterns, reusable across many `read_until_any`s
+///
+/// Searches by an Aho-Corasick automaton of at most `S` states ( 1 + total length of the patterns
+/// is enough ) in one pass over the input. Built in `const` context like
+/// `const DELIMITERS: MultiFinder<3> = MultiFinder::new([b"\r\n", b";", b"--"]);`.
+///
+/// When some matches start at the same index, the one listed first in the patterns is taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiFinder<'p, const N: usize, const S: usize = 64> {
+    patterns:     [&'p [u8]; N],
+    max_len:      usize,
+    /// bytes some patterns start with
+    first_bytes:  ByteSet,
+    n_states:     usize,
+    /* trie of the states, `0` is the root */
+    byte:         [u8; S],
+    first_child:  [u16; S],
+    next_sibling: [u16; S],
+    /* automaton */
+    fail:         [u16; S],
+    /// pattern ending at the state
+    output:       [u16; S],
+    /// nearest state by `fail` links having some output
+    dict:         [u16; S],
+}
+
+impl<'p, const N: usize> MultiFinder<'p, N> {
+    /// Build the automaton of `patterns` with the default capacity of 64 states
+    ///
+    /// - Panics if the patterns need more states ( use `with_capacity` )
+    pub const fn new(patterns: [&'p [u8]; N]) -> Self {
+        Self::with_capacity(patterns)
+    }
+}
+
+impl<'p, const N: usize, const S: usize> MultiFinder<'p, N, S> {
+    /// Build the automaton of `patterns` with the capacity of `S` states
+    ///
+    /// - Panics if the patterns need more than `S` states
+    pub const fn with_capacity(patterns: [&'p [u8]; N]) -> Self {
+        assert!(0 < S && S < NONE as usize, "`S` must be in `1..65535`");
+        let mut this = Self {
+            patterns,
+            max_len:      0,
+            first_bytes:  ByteSet::new(),
+            n_states:     1,
+            byte:         [0; S],
+            first_child:  [NONE; S],
+            next_sibling: [NONE; S],
+            fail:         [0; S],
+            output:       [NONE; S],
+            dict:         [NONE; S],
+        };
+
+        let mut p = 0; while p < N {
+            let pattern = patterns[p];
+            if pattern.len() > this.max_len {this.max_len = pattern.len()}
+            if !pattern.is_empty() {this.first_bytes = this.first_bytes.byte(pattern[0])}
+
+            let mut state = 0;
+            let mut i = 0; while i < pattern.len() {
+                state = match this.child(state, pattern[i]) {
+                    Some(child) => child,
+                    None => {
+                        assert!(this.n_states < S, "too many states for the capacity `S`");
+                        let child = this.n_states as u16;
+                        this.byte[child as usize]         = pattern[i];
+                        this.next_sibling[child as usize] = this.first_child[state as usize];
+                        this.first_child[state as usize]  = child;
+                        this.n_states += 1;
+                        child
+                    }
+                };
+                i += 1
+            }
+            if this.output[state as usize] == NONE {this.output[state as usize] = p as u16}
+            p += 1
+        }
+
+        /* failure links in BFS order */
+        let mut queue = [0u16; S];
+        let (mut head, mut tail) = (0, 1);
+        while head < tail {
+            let state = queue[head]; head += 1;
+            let mut child = this.first_child[state as usize];
+            while child != NONE {
+                let fail = if state == 0 {0} else {this.next(this.fail[state as usize], this.byte[child as usize])};
+                this.fail[child as usize] = fail;
+                this.dict[child as usize] = if this.output[fail as usize] != NONE {fail} else {this.dict[fail as usize]};
+                queue[tail] = child; tail += 1;
+                child = this.next_sibling[child as usize]
+            }
+        }
+
+        this
+    }
+
+    #[inline(always)] pub const fn patterns(&self) -> &[&'p [u8]; N] {
+        &self.patterns
+    }
+
+    #[inline] const fn child(&self, state: u16, byte: u8) -> Option<u16> {
+        let mut child = self.first_child[state as usize];
+        while child != NONE {
+            if self.byte[child as usize] == byte {return Some(child)}
+            child = self.next_sibling[child as usize]
+        }
+        None
+    }
+    #[inline] const fn next(&self, mut state: u16, byte: u8) -> u16 {
+        loop {
+            if let Some(child) = self.child(state, byte) {return child}
+            if state == 0 {return 0}
+            state = self.fail[state as usize]
+        }
+    }
+
+    /// Index where the first match starts and the index of the pattern matching there, or `None` if not found
+    ///
+    /// As a `Pattern`, `&MultiFinder` finds the start and end of the match instead.
+    pub fn find_pattern(&self, haystack: &[u8]) -> Option<(usize, usize)> {
+        let mut found = (self.output[0] != NONE).then_some((0, self.output[0] as usize));
+        let (mut state, mut i) = (0, 0);
+        while i < haystack.len() {
+            if let Some((start, _)) = found {
+                if i >= start + self.max_len {break}
+            }
+            if state == 0 && found.is_none() {
+                /* skip to where some pattern can start */
+                match {self.first_bytes}.find(unsafe {haystack.get_unchecked(i..)}) {
+                    Some((skip, _)) => i += skip,
+                    None            => break,
+                }
+            }
+
+            state = self.next(state, unsafe {*haystack.get_unchecked(i)});
+            i += 1;
+
+            let mut out = if self.output[state as usize] != NONE {state} else {self.dict[state as usize]};
+            while out != NONE {
+                let pattern = self.output[out as usize] as usize;
+                let start = i - self.patterns[pattern].len();
+                if found.is_none_or(|found| (start, pattern) < found) {
+                    found = Some((start, pattern))
+                }
+                out = self.dict[out as usize]
+            }
+        }
+        found
+    }
+}
+
+impl<const N: usize, const S: usize> Pattern for &MultiFinder<'_, N, S> {
+    #[inline] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.patterns.iter().find(|p| haystack.starts_with(p)).map(|p| p.len())
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        self.find_pattern(haystack).map(|(start, p)| (start, start + self.patterns[p].len()))
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Reader, Locator, Keywords, IdentClass, NumberError, NumberErrorKind, FloatSyntax};
+use crate::float::{scan, scan_sign, MAX_EXPONENT};
+
+mod sealed {pub trait Sealed {}}
+
+/// **`text` feature required**\
+/// Primitive integer types `read_integer` reads ( `u8` ... `u128`, `usize`, `i8` ... `i128`, `isize` )
+pub trait Integer: Copy + sealed::Sealed {
+    const SIGNED: bool;
+    #[doc(hidden)] const ZERO: Self;
+    /// `self * radix + digit`, or `self * radix - digit` for a negative literal, or `None` if overflows
+    #[doc(hidden)] fn push_digit(self, radix: u8, digit: u8, negative: bool) -> Option<Self>;
+    /// `magnitude`, or `-magnitude` if `negative`, or `None` if it doesn't fit
+    #[doc(hidden)] fn from_magnitude(magnitude: u128, negative: bool) -> Option<Self>;
+}
+macro_rules! integer {
+    ($($t:ty: $signed:literal),*) => {$(
+        impl sealed::Sealed for $t {}
+        impl Integer for $t {
+            const SIGNED: bool = $signed;
+            const ZERO: Self = 0;
+            #[inline(always)] fn push_digit(self, radix: u8, digit: u8, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(radix as Self)?;
+                if negative {shifted.checked_sub(digit as Self)} else {shifted.checked_add(digit as Self)}
+            }
+            #[inline] fn from_magnitude(magnitude: u128, negative: bool) -> Option<Self> {
+                if negative {
+                    (magnitude <= (Self::MIN as i128).unsigned_abs()).then(|| (magnitude as i128).wrapping_neg() as Self)
+                } else {
+                    Self::try_from(magnitude).ok()
+                }
+            }
+        }
+    )*};
+}
+integer! {
+    u8: false, u16: false, u32: false, u64: false, u128: false, usize: false,
+    i8: true,  i16: true,  i32: true,  i64: true,  i128: true,  isize: true
+}
+
+/// **`text` feature required**\
+/// Integer literal read by `read_integer_literal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerLiteral<'r, T: Integer> {
+    pub value:  T,
+    /// `16` for `0x`, `8` for `0o`, `2` for `0b`, or `10`
+    pub radix:  u32,
+    /// Type suffix like `"u8"`, `"i64"`, or `None` if not given
+    pub suffix: Option<&'r str>,
+}
+
+/// **`text` feature required**\
+/// Decimal literal read by `read_decimal`, viewing its parts in the input without rounding,
+/// like `123.4500e-2` as `integer: "123"`, `fraction: "4500"`, `exponent: -2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal<'r> {
+    pub negative: bool,
+    /// Digits before the decimal point ( may be empty like `.5` in C syntax, and may have `_`s in Rust syntax )
+    pub integer:  &'r str,
+    /// Digits after the decimal point ( may be empty, and may have `_`s in Rust syntax )
+    pub fraction: &'r str,
+    /// Exponent after `e` or `E`, or `0` if not given ( saturated at `±65536` )
+    pub exponent: i32,
+}
+
+impl Decimal<'_> {
+    /// The value as `mantissa * 10^-scale` keeping all the written digits, like `(1234500, 4)` for `123.4500`
+    /// and `(1200, 0)` for `1.2e3`, or `None` if the mantissa overflows `i128` or the exponent is saturated
+    pub fn to_i128(&self) -> Option<(i128, u32)> {
+        if self.is_saturated_fraction() {return None}
+        let n_fraction = self.fraction.bytes().filter(|b| *b != b'_').count() as i64;
+        let scale = u32::try_from((n_fraction - self.exponent as i64).max(0)).ok()?;
+        self.to_i128_with_scale(scale).map(|mantissa| (mantissa, scale))
+    }
+
+    /// The value as `mantissa * 10^-scale` for the given `scale` like `12345` for `123.4500` with scale `2`,
+    /// or `None` if the mantissa overflows `i128`, some non-zero digits don't fit in the scale or the exponent is saturated
+    pub fn to_i128_with_scale(&self, scale: u32) -> Option<i128> {
+        if self.is_saturated_fraction() {return None}
+        let digits = || self.integer.bytes().chain(self.fraction.bytes()).filter(|b| *b != b'_').map(|b| b - b'0');
+        let n_fraction = self.fraction.bytes().filter(|b| *b != b'_').count() as i64;
+
+        /* `10^shift` times the written digits, or dropping `-shift` of them */
+        let shift = scale as i64 + self.exponent as i64 - n_fraction;
+        let n_kept = digits().count() as i64 + shift.min(0);
+
+        let mut magnitude = 0u128;
+        for (i, d) in digits().enumerate() {
+            if (i as i64) < n_kept {
+                magnitude = magnitude.checked_mul(10)?.checked_add(d as u128)?
+            } else if d != 0 {
+                return None
+            }
+        }
+        if magnitude != 0 && shift > 0 {
+            magnitude = magnitude.checked_mul(10u128.checked_pow(shift.try_into().ok()?)?)?
+        }
+        i128::from_magnitude(magnitude, self.negative)
+    }
+
+    /// Whether the exponent is saturated at `-65536` with some non-zero digits, so the scale is unknown
+    /// ( saturated at `65536`, it's zero or overflows anyway )
+    fn is_saturated_fraction(&self) -> bool {
+        self.exponent as i64 <= -MAX_EXPONENT && self.integer.bytes().chain(self.fraction.bytes()).any(|b| matches!(b, b'1'..=b'9'))
+    }
+}
+
+/// Whether a magnitude, negative or not, fits an integer type
+type Fits = fn(u128, bool) -> bool;
+
+/// Rust integer type suffixes, mapped to whether a value fits the type
+const SUFFIXES: Keywords<'static, Fits, 12> = Keywords::<Fits, 12>::new([
+    ("u8", fits::<u8>), ("u16", fits::<u16>), ("u32", fits::<u32>), ("u64", fits::<u64>), ("u128", fits::<u128>), ("usize", fits::<usize>),
+    ("i8", fits::<i8>), ("i16", fits::<i16>), ("i32", fits::<i32>), ("i64", fits::<i64>), ("i128", fits::<i128>), ("isize", fits::<isize>),
+]).whole_words(IdentClass::Ascii);
+fn fits<T: Integer>(magnitude: u128, negative: bool) -> bool {
+    T::from_magnitude(magnitude, negative).is_some()
+}
+
+impl<'r, L: Locator> Reader<'r, L> {
+    /// **`text` feature required**\
+    /// Read a decimal integer literal like `42`, `-1111` ( `-` only for signed `T` ) as `T`.
+    ///
+    /// Or, returns `NumberError` without reading anything if no digit is found,
+    /// or if the integer overflows `T` ( with the span of the literal ).
+    pub fn read_integer<T: Integer>(&mut self) -> Result<T, NumberError> {
+        let start = self.checkpoint();
+        let negative = T::SIGNED && self.peek() == Some(&b'-');
+        if negative {self.advance_unchecked_by(1)}
+
+        let mut value = Some(T::ZERO);
+        let digits = self.read_while(|b: &u8| b.is_ascii_digit() && {
+            value = value.and_then(|v| v.push_digit(10, b - b'0', negative));
+            true
+        });
+
+        match value {
+            Some(value) if !digits.is_empty() => Ok(value),
+            Some(_) => {
+                self.restore(start);
+                Err(NumberError { kind: NumberErrorKind::NoDigits, span: self.span_since(start) })
+            }
+            None => {
+                let span = self.span_since(start);
+                self.restore(start);
+                Err(NumberError { kind: NumberErrorKind::Overflow, span })
+            }
+        }
+    }
+
+    /// **`text` feature required**\
+    /// Read an integer literal like `0xFF`, `0o755`, `0b1010`, `1_000_000`, `+42`, `10u8`, `-3i64` as `T`,
+    /// with the radix and the type suffix.
+    /// 
+    /// The radix prefix is one of lowercase `0x`, `0o`, `0b`, and `_`s may be put after any digit or the prefix.
+    /// `-` is accepted only for signed `T`, and a suffix only when it's not followed by an identifier character.
+    ///
+    /// Or, returns `NumberError` without reading anything if no digit is found, if a digit out of the radix
+    /// follows the digits ( like `0b12` ), or if the integer overflows `T` or the suffix type ( with the span of the literal ).
+    pub fn read_integer_literal<T: Integer>(&mut self) -> Result<IntegerLiteral<'r, T>, NumberError> {
+        let start = self.checkpoint();
+        let negative = match self.peek() {
+            Some(b'+')              => {self.advance_unchecked_by(1); false}
+            Some(b'-') if T::SIGNED => {self.advance_unchecked_by(1); true}
+            _ => false,
+        };
+
+        let radix = match (self.peek(), self.peek2()) {
+            (Some(b'0'), Some(b'x')) => 16,
+            (Some(b'0'), Some(b'o')) => 8,
+            (Some(b'0'), Some(b'b')) => 2,
+            (Some(b'0'..=b'9'), _)   => 10,
+            _ => {
+                self.restore(start);
+                return Err(NumberError { kind: NumberErrorKind::NoDigits, span: self.span_since(start) })
+            }
+        };
+        if radix != 10 {self.advance_unchecked_by(2)}
+
+        let (mut magnitude, mut n_digits) = (Some(0u128), 0);
+        self.skip_while(|b: &u8| *b == b'_' || match (*b as char).to_digit(radix) {
+            None => false,
+            Some(digit) => {
+                magnitude = magnitude.and_then(|m| m.checked_mul(radix as u128)?.checked_add(digit as u128));
+                n_digits += 1;
+                true
+            }
+        });
+        /* like `2` in `0b12`, ending the literal in the middle of the digits */
+        if radix < 10 && self.peek().is_some_and(u8::is_ascii_hexdigit) {
+            self.advance_unchecked_by(1);
+            let span = self.span_since(start);
+            self.restore(start);
+            return Err(NumberError { kind: NumberErrorKind::InvalidDigit, span })
+        }
+        if n_digits == 0 {
+            self.restore(start);
+            return Err(NumberError { kind: NumberErrorKind::NoDigits, span: self.span_since(start) })
+        }
+
+        let suffix = SUFFIXES.longest_prefix(self.remaining()).map(|(len, fits)| {
+            let suffix = unsafe {core::str::from_utf8_unchecked(self.buf.get_unchecked(self.index..self.index + len))};
+            self.advance_unchecked_by(len);
+            (suffix, fits)
+        });
+
+        let value = magnitude
+            .filter(|m| suffix.is_none_or(|(_, fits)| fits(*m, negative)))
+            .and_then(|m| T::from_magnitude(m, negative));
+        match value {
+            Some(value) => Ok(IntegerLiteral { value, radix, suffix: suffix.map(|(suffix, _)| suffix) }),
+            None => {
+                let span = self.span_since(start);
+                self.restore(start);
+                Err(NumberError { kind: NumberErrorKind::Overflow, span })
+            }
+        }
+    }
+
+    /// **`text` feature required**\
+    /// Read a decimal literal like `123.4500`, `-1e-9` in `syntax` as a `Decimal` viewing its parts,
+    /// to be converted exactly like `to_i128` without rounding through a float.
+    ///
+    /// Or, returns `NumberError` without reading anything if no literal is found ( including `inf` and `NaN` ).
+    pub fn read_decimal(&mut self, syntax: FloatSyntax) -> Result<Decimal<'r>, NumberError> {
+        let input = unsafe {self.buf.get_unchecked(self.index..)};
+        let (negative, sign) = scan_sign(input, syntax);
+        match scan(&input[sign..], syntax) {
+            Some((len, integer, fraction, exponent)) => {
+                self.advance_unchecked_by(sign + len);
+                Ok(Decimal {
+                    negative,
+                    integer:  unsafe {core::str::from_utf8_unchecked(integer)},
+                    fraction: unsafe {core::str::from_utf8_unchecked(fraction)},
+                    exponent: exponent as i32,
+                })
+            }
+            None => Err(NumberError { kind: NumberErrorKind::NoDigits, span: self.span_since(self.checkpoint()) }),
+        }
+    }
+}
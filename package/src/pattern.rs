@@ -0,0 +1,156 @@
+use core::ops::RangeInclusive;
+#[cfg(feature="alloc")] use alloc::{string::String, vec::Vec};
+use crate::finder::Finder;
+use crate::scan;
+
+/// What scanning operations of `Reader` ( `consume`, `read_until`, `skip_while`, `read_while`, `starts_with`, ... ) search for
+///
+/// - `u8` : the byte
+/// - `&[u8]`, `[u8; N]`, `&[u8; N]`, `&str` : the byte sequence
+/// - `Vec<u8>`, `&Vec<u8>`, `String`, `&String` : the byte sequence ( with `alloc` feature )
+/// - `RangeInclusive<u8>` like `b'a'..=b'z'` : a byte in the range
+/// - `ByteSet`, `&ByteSet` : a byte in the set
+/// - `Finder`, `&Finder` : the needle ( precompiled for searching )
+/// - `&MultiFinder` : any of the patterns ( the first listed one at the same start )
+/// - `FnMut(&u8)->bool` : a byte satisfying the predicate ( may be stateful )
+///
+/// A closure needs its argument type annotated like `|b: &u8| b.is_ascii_digit()`,
+/// or can be written as a path like `u8::is_ascii_digit`.
+///
+/// `Reader` clamps the lengths and indices an implementation returns to the haystack,
+/// so a wrong implementation never makes it read outside the input.
+pub trait Pattern {
+    /// Length of the match if `haystack` starts with this pattern, or `None` if not
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize>;
+
+    /// Length of the longest prefix of `haystack` made of repeated matches of this pattern
+    fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        let mut len = 0;
+        while let Some(n) = haystack.get(len..).and_then(|rest| self.match_prefix(rest)) {
+            if n == 0 {break} else {len = len.saturating_add(n)}
+        }
+        len
+    }
+
+    /// Start and end of the first match in `haystack`, or `None` if not found
+    fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        (0..=haystack.len()).find_map(|i| self
+            .match_prefix(&haystack[i..])
+            .map(|len| (i, i.saturating_add(len)))
+        )
+    }
+}
+
+impl Pattern for u8 {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        (haystack.first() == Some(self)).then_some(1)
+    }
+    #[inline] fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        scan::byte_len(*self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        haystack.iter().position(|b| b == self).map(|i| (i, i + 1))
+    }
+}
+
+impl Pattern for RangeInclusive<u8> {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        haystack.first().is_some_and(|b| self.contains(b)).then_some(1)
+    }
+    #[inline] fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        haystack.iter().take_while(|b| self.contains(b)).count()
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        haystack.iter().position(|b| self.contains(b)).map(|i| (i, i + 1))
+    }
+}
+
+impl<F: FnMut(&u8)->bool> Pattern for F {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        haystack.first().is_some_and(self).then_some(1)
+    }
+    #[inline] fn match_prefix_repeated(&mut self, haystack: &[u8]) -> usize {
+        haystack.iter().take_while(|b| self(b)).count()
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        haystack.iter().position(self).map(|i| (i, i + 1))
+    }
+}
+
+/// Pattern of the byte sequence `needle`
+#[inline] fn match_bytes(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+    haystack.starts_with(needle).then_some(needle.len())
+}
+#[inline] fn find_bytes(needle: &[u8], haystack: &[u8]) -> Option<(usize, usize)> {
+    Finder::new(needle).find_index(haystack).map(|i| (i, i + needle.len()))
+}
+
+impl Pattern for &[u8] {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self, haystack)
+    }
+}
+impl<const N: usize> Pattern for [u8; N] {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self, haystack)
+    }
+}
+impl<const N: usize> Pattern for &[u8; N] {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(*self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(*self, haystack)
+    }
+}
+impl Pattern for &str {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self.as_bytes(), haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self.as_bytes(), haystack)
+    }
+}
+
+#[cfg(feature="alloc")]
+impl Pattern for Vec<u8> {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self, haystack)
+    }
+}
+#[cfg(feature="alloc")]
+impl Pattern for &Vec<u8> {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self, haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self, haystack)
+    }
+}
+#[cfg(feature="alloc")]
+impl Pattern for String {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self.as_bytes(), haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self.as_bytes(), haystack)
+    }
+}
+#[cfg(feature="alloc")]
+impl Pattern for &String {
+    #[inline(always)] fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_bytes(self.as_bytes(), haystack)
+    }
+    #[inline] fn find(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        find_bytes(self.as_bytes(), haystack)
+    }
+}
@@ -0,0 +1,213 @@
+//! Word-at-a-time ( and SIMD where available ) scanning routines, each returning
+//! the length of the longest prefix of `bytes` whose every byte satisfies a condition
+
+use crate::byte_set::ByteSet;
+
+const LO: u64 = 0x0101_0101_0101_0101;
+const HI: u64 = 0x8080_8080_8080_8080;
+
+/// `0x80` at every non-zero byte of `x` and `0x00` at every zero byte
+#[inline(always)] const fn nonzero_bytes(x: u64) -> u64 {
+    (((x & !HI) + !HI) | x) & HI
+}
+/// `0x80` at every byte of `x` equal to `byte` and `0x00` at others
+#[inline(always)] const fn eq_bytes(x: u64, byte: u8) -> u64 {
+    !nonzero_bytes(x ^ (LO * byte as u64)) & HI
+}
+/// `0x80` at every byte of `x` in `start..=end` ( `end` <= 0x7F ) and `0x00` at others
+#[inline(always)] const fn in_ascii_range_bytes(x: u64, start: u8, end: u8) -> u64 {
+    let low7 = x & !HI;
+    /* per byte, `0x80 + end - low7` and `low7 + 0x80 - start` neither borrow nor carry */
+    (LO * (0x80 + end as u64) - low7) & (low7 + LO * (0x80 - start as u64)) & !x & HI
+}
+#[inline(always)] fn word_at(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(unsafe {*bytes.as_ptr().add(i).cast::<[u8; 8]>()})
+}
+/// Index of the first byte flagged in `mask` ( by `0x80` ) in a little-endian word
+#[inline(always)] fn first_flagged(mask: u64) -> usize {
+    (mask.trailing_zeros() / 8) as usize
+}
+
+/// Length of the prefix of bytes equal to `byte`
+#[inline] pub(crate) fn byte_len(byte: u8, bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i + 8 <= bytes.len() {
+        let others = nonzero_bytes(word_at(bytes, i) ^ (LO * byte as u64));
+        if others != 0 {return i + first_flagged(others)}
+        i += 8
+    }
+    i + bytes[i..].iter().take_while(|b| **b == byte).count()
+}
+
+/// Length of the prefix of ASCII whitespaces ( same as `u8::is_ascii_whitespace` )
+#[inline(always)] pub(crate) fn whitespace_len(bytes: &[u8]) -> usize {
+    /* most whitespaces between tokens are short, so are done inline without any setup */
+    for (i, b) in bytes.iter().enumerate() {
+        if !b.is_ascii_whitespace() {return i}
+        if i == 15 {return 16 + long_whitespace_len(&bytes[16..])}
+    }
+    bytes.len()
+}
+#[inline(never)] fn long_whitespace_len(bytes: &[u8]) -> usize {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    let i = unsafe {whitespace_len_sse2(bytes)};
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+    let i = 0;
+
+    whitespace_len_swar(bytes, i)
+}
+#[inline] fn whitespace_len_swar(bytes: &[u8], mut i: usize) -> usize {
+    while i + 8 <= bytes.len() {
+        let w = word_at(bytes, i);
+        let whitespaces = eq_bytes(w, b' ') | eq_bytes(w, b'\t') | eq_bytes(w, b'\n') | eq_bytes(w, b'\x0C') | eq_bytes(w, b'\r');
+        if whitespaces != HI {return i + first_flagged(!whitespaces & HI)}
+        i += 8
+    }
+    i + bytes[i..].iter().take_while(|b| b.is_ascii_whitespace()).count()
+}
+/// Index of the first 16-byte block of `bytes` including a non-whitespace, or of the last ( < 16 bytes ) block
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[inline] unsafe fn whitespace_len_sse2(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::*;
+
+    let (space, tab, lf, ff, cr) = (
+        _mm_set1_epi8(b' ' as i8), _mm_set1_epi8(b'\t' as i8), _mm_set1_epi8(b'\n' as i8),
+        _mm_set1_epi8(b'\x0C' as i8), _mm_set1_epi8(b'\r' as i8),
+    );
+    let mut i = 0;
+    while i + 16 <= bytes.len() {
+        let v = _mm_loadu_si128(bytes.as_ptr().add(i).cast());
+        let whitespaces = _mm_or_si128(
+            _mm_or_si128(_mm_cmpeq_epi8(v, space), _mm_cmpeq_epi8(v, tab)),
+            _mm_or_si128(_mm_cmpeq_epi8(v, lf), _mm_or_si128(_mm_cmpeq_epi8(v, ff), _mm_cmpeq_epi8(v, cr))),
+        );
+        if _mm_movemask_epi8(whitespaces) != 0xFFFF {break}
+        i += 16
+    }
+    i
+}
+
+/// Length of the prefix of bytes in `set`
+#[inline] pub(crate) fn set_len(set: &ByteSet, bytes: &[u8]) -> usize {
+    if *set == ByteSet::WHITESPACE {return whitespace_len(bytes)}
+
+    /* short runs are done here without any setup */
+    let head = bytes.len().min(16);
+    let mut i = bytes[..head].iter().take_while(|b| set.contains(**b)).count();
+    if i < 16 {return i}
+
+    /* others than up to 4 ranges ( 4 ASCII ranges without SSE2 ) are done byte by byte */
+    let ranges = set.ranges::<4>();
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    if let Some(ranges) = &ranges {
+        i += unsafe {ranges_len_sse2(ranges, &bytes[i..])};
+    }
+
+    if let Some(ranges) = ranges.filter(|ranges| ranges.iter().all(|(_, end)| end.is_ascii())) {
+        i = ranges_len_swar(&ranges, bytes, i)
+    }
+    i + bytes[i..].iter().take_while(|b| set.contains(**b)).count()
+}
+#[inline] fn ranges_len_swar<const N: usize>(ascii_ranges: &[(u8, u8); N], bytes: &[u8], mut i: usize) -> usize {
+    while i + 8 <= bytes.len() {
+        let w = word_at(bytes, i);
+        let in_ranges = ascii_ranges.iter().fold(0, |flags, (start, end)| flags | in_ascii_range_bytes(w, *start, *end));
+        if in_ranges != HI {return i + first_flagged(!in_ranges & HI)}
+        i += 8
+    }
+    i
+}
+
+/// Index of the first 16-byte block of `bytes` including a byte out of all `ranges`, or of the last ( < 16 bytes ) block
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[inline] unsafe fn ranges_len_sse2<const N: usize>(ranges: &[(u8, u8); N], bytes: &[u8]) -> usize {
+    use core::arch::x86_64::*;
+
+    let bounds = ranges.map(|(start, end)| (_mm_set1_epi8(start as i8), _mm_set1_epi8(end as i8)));
+    let mut i = 0;
+    while i + 16 <= bytes.len() {
+        let v = _mm_loadu_si128(bytes.as_ptr().add(i).cast());
+        let mut in_ranges = _mm_setzero_si128();
+        for (start, end) in bounds {
+            /* start <= v && v <= end, in unsigned */
+            in_ranges = _mm_or_si128(in_ranges, _mm_and_si128(
+                _mm_cmpeq_epi8(_mm_max_epu8(v, start), v),
+                _mm_cmpeq_epi8(_mm_min_epu8(v, end), v),
+            ));
+        }
+        if _mm_movemask_epi8(in_ranges) != 0xFFFF {break}
+        i += 16
+    }
+    i
+}
+
+/// Number of bytes equal to `byte`
+#[inline] pub(crate) fn count_byte(byte: u8, bytes: &[u8]) -> usize {
+    let (mut count, mut i) = (0, 0);
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        use core::arch::x86_64::*;
+        let target = _mm_set1_epi8(byte as i8);
+        while i + 16 <= bytes.len() {
+            let v = _mm_loadu_si128(bytes.as_ptr().add(i).cast());
+            count += (_mm_movemask_epi8(_mm_cmpeq_epi8(v, target)) as u32).count_ones() as usize;
+            i += 16
+        }
+    }
+
+    while i + 8 <= bytes.len() {
+        count += eq_bytes(word_at(bytes, i), byte).count_ones() as usize;
+        i += 8
+    }
+    count + bytes[i..].iter().filter(|b| **b == byte).count()
+}
+
+/// Index of the first byte equal to `byte`, or `None` if not found
+#[inline] pub(crate) fn find_byte(byte: u8, bytes: &[u8]) -> Option<usize> {
+    let mut i = 0;
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        use core::arch::x86_64::*;
+        let target = _mm_set1_epi8(byte as i8);
+        while i + 16 <= bytes.len() {
+            let v = _mm_loadu_si128(bytes.as_ptr().add(i).cast());
+            let found = _mm_movemask_epi8(_mm_cmpeq_epi8(v, target)) as u32;
+            if found != 0 {return Some(i + found.trailing_zeros() as usize)}
+            i += 16
+        }
+    }
+
+    while i + 8 <= bytes.len() {
+        let found = eq_bytes(word_at(bytes, i), byte);
+        if found != 0 {return Some(i + first_flagged(found))}
+        i += 8
+    }
+    bytes[i..].iter().position(|b| *b == byte).map(|at| i + at)
+}
+
+/// Index of the last byte equal to `byte`, or `None` if not found
+#[inline] pub(crate) fn rfind_byte(byte: u8, bytes: &[u8]) -> Option<usize> {
+    let mut end = bytes.len();
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        use core::arch::x86_64::*;
+        let target = _mm_set1_epi8(byte as i8);
+        while end >= 16 {
+            let v = _mm_loadu_si128(bytes.as_ptr().add(end - 16).cast());
+            let found = _mm_movemask_epi8(_mm_cmpeq_epi8(v, target)) as u32;
+            if found != 0 {return Some(end - 16 + (31 - found.leading_zeros()) as usize)}
+            end -= 16
+        }
+    }
+
+    while end >= 8 {
+        let found = eq_bytes(word_at(bytes, end - 8), byte);
+        if found != 0 {return Some(end - 8 + (7 - found.leading_zeros() / 8) as usize)}
+        end -= 8
+    }
+    bytes[..end].iter().rposition(|b| *b == byte)
+}
@@ -0,0 +1,30 @@
+use core::ops::Range;
+use crate::location::Location;
+
+/// Range of some bytes in the input, with the locations of its both ends if the reader tracks them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Index where the bytes start
+    pub start: usize,
+    /// Index where the bytes end (exclusive)
+    pub end:   usize,
+    /// Location where the bytes start, or `None` if the reader doesn't track it
+    pub start_location: Option<Location>,
+    /// Location where the bytes end (exclusive), or `None` if the reader doesn't track it
+    pub end_location:   Option<Location>,
+}
+
+impl Span {
+    /// Number of bytes in the span
+    #[inline(always)] pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+    #[inline(always)] pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    /// `start..end`, available for indexing the input like `&input[span.range()]`
+    /// ( for a reader with base index, the enclosing document instead of the input )
+    #[inline(always)] pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
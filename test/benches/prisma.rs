@@ -35,6 +35,35 @@ datasource db {
     })
 }
 
+#[bench] fn read_an_indented_schema_dot_prisma(b: &mut test::Bencher) {
+    use prisma::*;
+
+    let schema = SCHEMA.replace('\n', "\n\t\t\t\t                                                                ");
+    b.iter(|| {
+        let mut r = byte_reader::Reader::new(schema.as_bytes());
+        Schema::parse(&mut r)
+    })
+}
+
+#[bench] fn read_while_byte_set(b: &mut test::Bencher) {
+    use byte_reader::ByteSet;
+
+    let input = "the_quick_brown_fox_jumps_over_the_lazy_dog_".repeat(100) + ";";
+    b.iter(|| {
+        let mut r = byte_reader::Reader::new(input.as_bytes());
+        r.read_while(ByteSet::IDENT_CONTINUE).len()
+    })
+}
+
+#[bench] fn advance_with_line_column(b: &mut test::Bencher) {
+    let input = "SELECT id, name\n  FROM users\n  WHERE id = 1;\n".repeat(100);
+    b.iter(|| {
+        let mut r = byte_reader::Reader::with_locator(input.as_bytes(), byte_reader::LineColumn::new());
+        r.advance_by(input.len());
+        r.line()
+    })
+}
+
 mod prisma {
     use byte_reader::Reader;
 
@@ -50,7 +79,7 @@ mod prisma {
     #[cfg(not(feature="text"))]
     fn read_string<'r>(r: &mut Reader<'r>) -> Option<&'r str> {
         r.consume("\"")?;
-        let string = r.read_while(|b| b != &b'"');
+        let string = r.read_while(|b: &u8| b != &b'"');
         r.consume("\"").unwrap();
         Some(std::str::from_utf8(string).unwrap())
     }
@@ -124,7 +153,7 @@ mod prisma {
             r.skip_whitespace();
 
             let name = std::str::from_utf8(
-                r.read_while(|b| matches!(b, b'a'..=b'z' | b'A'..=b'Z'))
+                r.read_while(|b: &u8| matches!(b, b'a'..=b'z' | b'A'..=b'Z'))
             ).unwrap();
             r.skip_whitespace();
 
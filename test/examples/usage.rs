@@ -1,6 +1,6 @@
-use byte_reader::Reader;
+use byte_reader::{Reader, LineColumn};
 
-#[cfg(not(feature="location"))] fn main() {
+fn main() {
     // Get a input from a File, standard input, or others
     // Input must implement `AsRef<[u8]>`
     let sample_input = "Hello,    byte_reader!".as_bytes();
@@ -13,23 +13,23 @@ use byte_reader::Reader;
     r.consume("Hello").unwrap();
     r.consume(",").unwrap();
     r.skip_whitespace();
-    let name = r.read_while(|b| b != &b'!'); // b"byte_reader"
+    let name = r.read_while(|b: &u8| b != &b'!'); // b"byte_reader"
     let name = String::from_utf8_lossy(name).to_string();
     r.consume("!").unwrap();
 
     println!("Greeted to `{name}`.");
-}
 
-#[cfg(feature="location")] fn main() {
-    let mut r = Reader::new(b"Hello,    byte_reader!");
+
+    // Create `r` tracking its line and column
+    let mut r = Reader::with_locator(b"Hello,    byte_reader!", LineColumn::new());
 
     r.consume("Hello").unwrap();
     r.consume(",").unwrap();
     r.skip_whitespace();
-    let name_line   = r.line;   // 1
-    let name_column = r.column; // 11
-    let name_index  = r.index;  // 10
-    let name = r.read_while(|b| b != &b'!'); // b"byte_reader"
+    let name_line   = r.line();   // 1
+    let name_column = r.column(); // 11
+    let name_index  = r.index();  // 10
+    let name = r.read_while(|b: &u8| b != &b'!'); // b"byte_reader"
     let name = String::from_utf8_lossy(name).to_string();
     r.consume("!").unwrap();
 
@@ -5,4 +5,8 @@ fn is_sync<T: Sync>() {}
 fn assert_impls() {
     is_send::<byte_reader::Reader>();
     is_sync::<byte_reader::Reader>();
+    is_send::<byte_reader::Reader<byte_reader::LineColumn>>();
+    is_sync::<byte_reader::Reader<byte_reader::LineColumn>>();
+    is_send::<byte_reader::Reader<byte_reader::Indexed>>();
+    is_sync::<byte_reader::Reader<byte_reader::Indexed>>();
 }
@@ -1,4 +1,9 @@
-use byte_reader::Reader;
+use byte_reader::{Reader, ParseError, Expected, Span, Location, Locator, LineColumn};
+
+fn loc(line: usize, column: usize) -> Option<Location> {
+    Some(Location { line, column })
+}
+
 
 #[test] fn test_whitespace() {
     let mut r = Reader::new(b" ");
@@ -28,105 +33,774 @@ use byte_reader::Reader;
 }
 
 #[test] fn test_unwind() {
-    let mut r = Reader::new(b"Hello, world!\nMy name is byte_reader!");
-    r.read_while(|b| b != &b'\n');
+    let mut r = Reader::with_locator(b"Hello, world!\nMy name is byte_reader!", LineColumn::new());
+    r.read_while(|b: &u8| b != &b'\n');
     assert_eq!(r.remaining(), b"\nMy name is byte_reader!");
-    #[cfg(feature="location")] assert_eq!(r.line,   1);
-    #[cfg(feature="location")] assert_eq!(r.column, 14);
+    assert_eq!(r.line(),   1);
+    assert_eq!(r.column(), 14);
     r.advance_by(3);
     assert_eq!(r.remaining(), b" name is byte_reader!");
-    #[cfg(feature="location")] assert_eq!(r.line,   2);
-    #[cfg(feature="location")] assert_eq!(r.column, 3);
+    assert_eq!(r.line(),   2);
+    assert_eq!(r.column(), 3);
     r.unwind_by(2);
     assert_eq!(r.remaining(), b"My name is byte_reader!");
-    #[cfg(feature="location")] assert_eq!(r.line,   2);
-    #[cfg(feature="location")] assert_eq!(r.column, 1);
+    assert_eq!(r.line(),   2);
+    assert_eq!(r.column(), 1);
     r.unwind_by(2);
     assert_eq!(r.remaining(), b"!\nMy name is byte_reader!");
-    #[cfg(feature="location")] assert_eq!(r.line,   1);
-    #[cfg(feature="location")] assert_eq!(r.column, 13);
+    assert_eq!(r.line(),   1);
+    assert_eq!(r.column(), 13);
 
-    let mut r = Reader::new(b"Hello!\nMy name is!\nkanarus!");
-    r.read_while(|b| b != &b'\n');
+    let mut r = Reader::with_locator(b"Hello!\nMy name is!\nkanarus!", LineColumn::new());
+    r.read_while(|b: &u8| b != &b'\n');
     r.advance_by(1);
-    r.read_while(|b| b != &b'\n');
+    r.read_while(|b: &u8| b != &b'\n');
     r.advance_by(1);
     assert_eq!(r.remaining(), b"kanarus!");
-    #[cfg(feature="location")] assert_eq!(r.line,   3);
-    #[cfg(feature="location")] assert_eq!(r.column, 1);
+    assert_eq!(r.line(),   3);
+    assert_eq!(r.column(), 1);
     r.unwind_by(1);
     assert_eq!(r.remaining(), b"\nkanarus!");
-    #[cfg(feature="location")] assert_eq!(r.line,   2);
-    #[cfg(feature="location")] assert_eq!(r.column, 12);
+    assert_eq!(r.line(),   2);
+    assert_eq!(r.column(), 12);
     r.unwind_by(1);
     assert_eq!(r.remaining(), b"!\nkanarus!");
-    #[cfg(feature="location")] assert_eq!(r.line,   2);
-    #[cfg(feature="location")] assert_eq!(r.column, 11);
+    assert_eq!(r.line(),   2);
+    assert_eq!(r.column(), 11);
+
+}
+
+#[test] fn test_newline_config() {
+    use byte_reader::Newline;
+
+    let input = b"a\r\nb\rc\nd";
+    let positions = [(0, 1, 1), (1, 1, 2), (2, 1, 3), (3, 2, 1), (4, 2, 2), (5, 3, 1), (6, 3, 2), (7, 4, 1), (8, 4, 2)];
+
+    let mut r = Reader::with_locator(input, LineColumn::new().newline(Newline::ANY));
+    for (index, line, column) in positions {
+        r.seek(index);
+        assert_eq!((r.line(), r.column()), (line, column), "at {index}");
+    }
+    for (index, line, column) in positions.into_iter().rev() {
+        r.seek(index);
+        assert_eq!((r.line(), r.column()), (line, column), "back at {index}");
+    }
+
+    let mut r = Reader::with_locator(input, LineColumn::new().newline(Newline::CR | Newline::LF));
+    r.read_until("b");
+    assert_eq!((r.line(), r.column()), (3, 1));
+
+    let mut r = Reader::with_locator(input, LineColumn::new().newline(Newline::CRLF));
+    r.read_until("d");
+    assert_eq!((r.line(), r.column()), (2, 5));
+    r.unwind_by(4);
+    assert_eq!((r.line(), r.column()), (2, 1));
+    r.unwind_by(1);
+    assert_eq!((r.line(), r.column()), (1, 3));
+
+    let input = "a\u{2028}b\u{2029}c".as_bytes();
+    let mut r = Reader::with_locator(input, LineColumn::new().newline(Newline::LF | Newline::UNICODE));
+    r.read_until("c");
+    assert_eq!((r.line(), r.column()), (3, 1));
+    r.unwind_by(4);
+    assert_eq!((r.line(), r.column()), (2, 1));
+    r.unwind_by(1);
+    assert_eq!((r.line(), r.column()), (1, 4));
+}
+
+#[test] fn test_column_config() {
+    use byte_reader::ColumnUnit;
+
+    let mut r = Reader::with_locator(b"\tab\t\tc\n\td", LineColumn::new().tab_width(4));
+    r.advance_by(1);
+    assert_eq!(r.column(), 5);
+    r.advance_by(3);
+    assert_eq!(r.column(), 9);
+    r.advance_by(2);
+    assert_eq!(r.column(), 14);
+    r.read_until("d");
+    assert_eq!((r.line(), r.column()), (2, 5));
+    r.unwind_by(4);
+    assert_eq!((r.line(), r.column()), (1, 9));
+
+    let input = "aé中🦀b\n".as_bytes();
+    let mut r = Reader::with_locator(input, LineColumn::new().column_unit(ColumnUnit::Char));
+    r.read_until("b");
+    assert_eq!(r.column(), 5);
+    r.unwind_by(4);
+    assert_eq!(r.column(), 4);
+
+    let mut r = Reader::with_locator(input, LineColumn::new().column_unit(ColumnUnit::Utf16));
+    r.read_until("b");
+    assert_eq!(r.column(), 6);
+    r.unwind_by(4);
+    assert_eq!(r.column(), 4);
+
+    let mut r = Reader::with_locator(input, LineColumn::new());
+    r.read_until("b");
+    assert_eq!(r.column(), 11);
+}
+
+#[test] fn test_seek() {
+    let mut r = Reader::with_locator(b"Hello!\nMy name is!\nkanarus!", LineColumn::new());
+
+    r.seek(20);
+    assert_eq!(r.index(), 20);
+    assert_eq!(r.remaining(), b"anarus!");
+    assert_eq!((r.line(), r.column()), (3, 2));
+
+    r.seek(10);
+    assert_eq!(r.remaining(), b"name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (2, 4));
+
+    r.seek_relative(-5);
+    assert_eq!(r.remaining(), b"!\nMy name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (1, 6));
+
+    r.seek_relative(2);
+    assert_eq!(r.remaining(), b"My name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (2, 1));
+
+    r.seek_relative(-100);
+    assert_eq!(r.index(), 0);
+    assert_eq!((r.line(), r.column()), (1, 1));
+
+    r.seek(100);
+    assert_eq!(r.index(), 27);
+    assert_eq!(r.remaining(), b"");
+    assert_eq!((r.line(), r.column()), (3, 9));
+}
+
+#[test] fn test_checkpoint() {
+    let mut r = Reader::with_locator(b"Hello!\nMy name is!\nkanarus!", LineColumn::new());
+    r.advance_by(3);
+    let cp = r.checkpoint();
+    assert_eq!(cp.index(), 3);
+
+    r.read_until("kanarus");
+    assert_eq!(r.remaining(), b"kanarus!");
+    assert_eq!((r.line(), r.column()), (3, 1));
+
+    r.restore(cp);
+    assert_eq!(r.remaining(), b"lo!\nMy name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (1, 4));
+
+    let mut attempt = r.clone();
+    assert!(attempt.consume("lo!\nMy").is_some());
+    assert_eq!((attempt.line(), attempt.column()), (2, 3));
+    assert_eq!(r.remaining(), b"lo!\nMy name is!\nkanarus!");
+
+    r.restore(attempt.checkpoint());
+    assert_eq!(r.remaining(), b" name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (2, 3));
+}
+
+#[test] fn test_attempt() {
+    let mut r = Reader::with_locator(b"key =\n  value;\nkey = ;", LineColumn::new());
+
+    let pair = r.attempt(|r| {
+        let key = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+        r.skip_whitespace(); r.consume("=")?; r.skip_whitespace();
+        let value = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+        r.consume(";")?;
+        Some((key, value))
+    });
+    assert_eq!(pair, Some((&b"key"[..], &b"value"[..])));
+    assert_eq!((r.line(), r.column()), (2, 9));
+    r.skip_whitespace();
+
+    let pair = r.attempt(|r| {
+        let key = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+        r.skip_whitespace(); r.consume("=")?; r.skip_whitespace();
+        r.consume("value")?;
+        Some(key)
+    });
+    assert_eq!(pair, None);
+    assert_eq!(r.remaining(), b"key = ;");
+    assert_eq!((r.line(), r.column()), (3, 1));
+
+    let key: Result<_, ParseError> = r.attempt_result(|r| {
+        let key = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+        r.skip_whitespace(); r.expect("=")?; r.skip_whitespace();
+        r.expect("value")?;
+        Ok(key)
+    });
+    assert_eq!(key.unwrap_err().expected, Expected::Token(b"value"));
+    assert_eq!(r.remaining(), b"key = ;");
+    assert_eq!((r.line(), r.column()), (3, 1));
+}
+
+#[test] fn test_recognize() {
+    fn number<'r, L: Locator>(r: &mut Reader<'r, L>) -> Option<&'r [u8]> {
+        r.recognize(|r| {
+            r.next_if(|b| matches!(b, b'+' | b'-'));
+            r.next_if(|b| b.is_ascii_digit())?;
+            r.skip_while(|b: &u8| b.is_ascii_digit());
+            if r.consume(".").is_some() {
+                r.skip_while(|b: &u8| b.is_ascii_digit());
+            }
+            if r.next_if(|b| matches!(b, b'e' | b'E')).is_some() {
+                r.next_if(|b| matches!(b, b'+' | b'-'));
+                r.next_if(|b| b.is_ascii_digit())?;
+                r.skip_while(|b: &u8| b.is_ascii_digit());
+            }
+            Some(())
+        })
+    }
+
+    let input = String::from("-12.5e+3, 42\n+1e, 7");
+    let mut r = Reader::with_locator(input.as_bytes(), LineColumn::new());
+
+    let n = number(&mut r);
+    assert_eq!(n, Some(&b"-12.5e+3"[..]));
+    r.consume(", ").unwrap();
+    assert_eq!(number(&mut r), Some(&b"42"[..]));
+    r.skip_whitespace();
+    assert_eq!(number(&mut r), None);
+    assert_eq!(r.remaining(), b"+1e, 7");
+    assert_eq!((r.line(), r.column()), (2, 1));
+
+    r.consume("+1e, ").unwrap();
+    let (n, span) = r.recognize_spanned(|r| r.next_if(|b| b.is_ascii_digit())).unwrap();
+    assert_eq!(n, b"7");
+    assert_eq!(span.range(), 18..19);
+    assert_eq!(span.start_location, loc(2, 6));
+}
+
+#[test] #[should_panic] fn test_restore_foreign_checkpoint() {
+    let mut long = Reader::new(b"Hello, world!"); long.advance_by(10);
+
+    let mut short = Reader::new(b"Hi!");
+    short.restore(long.checkpoint());
+}
+
+#[test] #[should_panic] fn test_restore_foreign_checkpoint_in_range() {
+    let short = Reader::new(b"Hi!");
+
+    let mut long = Reader::new(b"Hello, world!"); long.advance_by(10);
+    long.restore(short.checkpoint());
+}
+
+#[test] fn test_line_index() {
+    use byte_reader::{LineIndex, Indexed};
+
+    let input = b"Hello!\nMy name is!\nkanarus!";
+    assert_eq!(LineIndex::count_lines(input), 3);
+    assert!(LineIndex::build(input, &mut [0; 2]).is_none());
+
+    let mut storage = [0; 3];
+    let index = LineIndex::build(input, &mut storage).unwrap();
+    assert_eq!(index.n_lines(), 3);
+    assert_eq!(index.line_start(0), None);
+    assert_eq!(index.line_start(2), Some(7));
+    assert_eq!(index.line_start(4), None);
+    assert_eq!(Some(index.locate(0)),  loc(1, 1));
+    assert_eq!(Some(index.locate(6)),  loc(1, 7));
+    assert_eq!(Some(index.locate(7)),  loc(2, 1));
+    assert_eq!(Some(index.locate(18)), loc(2, 12));
+    assert_eq!(Some(index.locate(27)), loc(3, 9));
+
+    let mut r = Reader::with_locator(input, Indexed::new(&index));
+    r.read_until("kanarus");
+    assert_eq!((r.line(), r.column()), (3, 1));
+    r.unwind_by(1);
+    assert_eq!(r.remaining(), b"\nkanarus!");
+    assert_eq!((r.line(), r.column()), (2, 12));
+    r.unwind_by(13);
+    assert_eq!(r.remaining(), b"!\nMy name is!\nkanarus!");
+    assert_eq!((r.line(), r.column()), (1, 6));
+    r.advance_by(2);
+    assert_eq!((r.line(), r.column()), (2, 1));
+}
+
+#[test] #[should_panic] fn test_foreign_line_index() {
+    let mut storage = [0; 1];
+    let index = byte_reader::LineIndex::build(b"Hi!", &mut storage).unwrap();
+    Reader::with_locator(b"Hello!", byte_reader::Indexed::new(&index)).advance_by(5);
+}
+
+#[test] fn test_origin() {
+    use byte_reader::{LineIndex, Indexed};
+
+    let document = b"query!(\"SELECT *\n  FROM users\")";
+    let fragment = &document[8..29];
+    assert_eq!(fragment, b"SELECT *\n  FROM users");
+
+    let mut r = Reader::with_origin(fragment, 8, 1, 9);
+    assert_eq!((r.index(), r.line(), r.column()), (8, 1, 9));
+    r.read_until("\n");
+    assert_eq!((r.index(), r.line(), r.column()), (16, 1, 17));
+    r.advance_by(1);
+    r.skip_whitespace();
+    assert_eq!((r.index(), r.line(), r.column()), (19, 2, 3));
 
+    let err = r.expect("WHERE").unwrap_err();
+    assert_eq!((err.index, err.location), (19, loc(2, 3)));
+    let span = r.span_of(&fragment[11..15]).unwrap();
+    assert_eq!(&document[span.range()], b"FROM");
+    assert_eq!((span.start_location, span.end_location), (loc(2, 3), loc(2, 7)));
+
+    r.unwind_by(12);
+    assert_eq!((r.index(), r.line(), r.column()), (8, 1, 9));
+    r.seek(19);
+    assert_eq!((r.index(), r.line(), r.column()), (19, 2, 3));
+    r.seek(0);
+    assert_eq!((r.index(), r.line(), r.column()), (8, 1, 9));
+
+    let cp = r.checkpoint();
+    r.advance_by(4);
+    assert_eq!(r.span_since(cp).range(), 8..12);
+    r.restore(cp);
+    assert_eq!(r.index(), 8);
+
+    let mut storage = [0; 2];
+    let index = LineIndex::build(fragment, &mut storage).unwrap();
+    let mut r = Reader::with_locator_at(fragment, 8, Indexed::new(&index).origin(1, 9));
+    r.advance_by(4);
+    assert_eq!((r.index(), r.line(), r.column()), (12, 1, 13));
+    r.advance_by(7);
+    assert_eq!((r.index(), r.line(), r.column()), (19, 2, 3));
 }
 
 #[test] fn test_read_while() {
     let mut r = Reader::new(b"Hello,  world!");
 
-    let read = r.read_while(|b| !b.is_ascii_whitespace());
+    let read = r.read_while(|b: &u8| !b.is_ascii_whitespace());
     assert_eq!(read, b"Hello,");
     assert_eq!(r.remaining(), b"  world!");
 
-    let read = r.read_while(|b| b.is_ascii_whitespace());
+    let read = r.read_while(|b: &u8| b.is_ascii_whitespace());
     assert_eq!(read, b"  ");
     assert_eq!(r.remaining(), b"world!");
 
-    let read = r.read_while(|b| b.is_ascii_alphabetic());
+    let read = r.read_while(|b: &u8| b.is_ascii_alphabetic());
     assert_eq!(read, b"world");
     assert_eq!(r.remaining(), b"!");
 
-    let read = r.read_while(|_| true);
+    let read = r.read_while(|_: &u8| true);
     assert_eq!(read, b"!");
     assert_eq!(r.remaining(), b"");
 }
 
 #[test] fn test_read_until() {
-    let mut r = Reader::new(b"Hello, world!");
+    let mut r = Reader::with_locator(b"Hello, world!", LineColumn::new());
 
     let read = r.read_until(b" ");
     assert_eq!(read,          b"Hello,");
     assert_eq!(r.remaining(), b" world!");
-    #[cfg(feature="location")]
-    assert_eq!(r.column,     7);
+    assert_eq!(r.column(),     7);
 
     let read = r.read_until(b"rl");
     assert_eq!(read,          b" wo");
     assert_eq!(r.remaining(), b"rld!");
-    #[cfg(feature="location")]
-    assert_eq!(r.column,     10);
+    assert_eq!(r.column(),     10);
 
 
-    let mut r = Reader::new(b"Hello, world!");
+    let mut r = Reader::with_locator(b"Hello, world!", LineColumn::new());
 
     r.consume("Hello").unwrap();
     let read = r.read_until(b"rl");
     assert_eq!(read,          b", wo");
     assert_eq!(r.remaining(), b"rld!");
-    #[cfg(feature="location")]
-    assert_eq!(r.column,     10);
+    assert_eq!(r.column(),     10);
 
 
-    let mut r = Reader::new(b"Hello, world!");
+    let mut r = Reader::with_locator(b"Hello, world!", LineColumn::new());
 
     let read = r.read_until(b"");
     assert_eq!(read,          b"");
     assert_eq!(r.remaining(), b"Hello, world!");
-    #[cfg(feature="location")]
-    assert_eq!(r.column,     1);
+    assert_eq!(r.column(),     1);
 
 
-    let mut r = Reader::new(b"Hello, world!");
+    let mut r = Reader::with_locator(b"Hello, world!", LineColumn::new());
 
     let read = r.read_until(b"xyz");
     assert_eq!(read,          b"Hello, world!");
     assert_eq!(r.remaining(), b"");
-    #[cfg(feature="location")]
-    assert_eq!(r.column,     14);
+    assert_eq!(r.column(),     14);
+}
+
+#[test] fn test_pattern() {
+    let mut r = Reader::new(b"aaab 2024-01-01;rest");
+
+    assert!(r.starts_with(b'a'));
+    assert!(r.starts_with(b'a'..=b'c'));
+    assert!(!r.starts_with("aab"));
+    assert_eq!(r.read_while(b'a'), b"aaa");
+    assert_eq!(r.consume_oneof([b"x", b"b"]), Some(1));
+    r.skip_while(b' ');
+
+    let mut n_dashes = 0;
+    let date = r.read_while(|b: &u8| match b {
+        b'-' => {n_dashes += 1; n_dashes <= 2}
+        _    => b.is_ascii_digit(),
+    });
+    assert_eq!(date, b"2024-01-01");
+
+    assert_eq!(r.read_until(b'r'), b";");
+    assert!(r.consume([b'r', b'e']).is_some());
+    assert_eq!(r.read_until(b's'..=b't'), b"");
+    assert_eq!(r.read_while("st"), b"st");
+    assert_eq!(r.remaining(), b"");
+
+    let mut r = Reader::new(b"ababa");
+    r.skip_while("ab");
+    assert_eq!(r.remaining(), b"a");
+    r.skip_while("");
+    assert_eq!(r.remaining(), b"a");
+}
+
+#[test] fn test_byte_set() {
+    use byte_reader::ByteSet;
+
+    const KEBAB: ByteSet = ByteSet::LOWERCASE.union(ByteSet::new().bytes(b"-"));
+    assert!(KEBAB.contains(b'-') && KEBAB.contains(b'q') && !KEBAB.contains(b'Q'));
+    assert!(ByteSet::new().range(0x80..=0xFF).contains(0xFF));
+    assert_eq!(ByteSet::ASCII.complement(), ByteSet::new().range(0x80..=0xFF));
+    assert_eq!(!ByteSet::ASCII | ByteSet::ASCII, ByteSet::new().range(0..=255));
+    assert_eq!(ByteSet::HEX_DIGIT & ByteSet::UPPERCASE, ByteSet::new().range(b'A'..=b'F'));
+    for b in 0..=u8::MAX {
+        assert_eq!(ByteSet::WHITESPACE.contains(b), b.is_ascii_whitespace());
+        assert_eq!(ByteSet::HEX_DIGIT.contains(b), b.is_ascii_hexdigit());
+        assert_eq!(ByteSet::ALPHANUMERIC.contains(b), b.is_ascii_alphanumeric());
+    }
+
+    let all_bytes: [u8; 256] = core::array::from_fn(|i| i as u8);
+    for (set, runs) in [
+        (ByteSet::new().range(0..=255),                                   &[(0, 255)][..]),
+        (ByteSet::new().range(60..=130).range(191..=192).range(250..=255), &[(60, 130), (191, 192), (250, 255)]),
+        (ByteSet::new().range(0..=63).range(64..=64).bytes(&[66, 68, 70]),  &[(0, 64), (66, 66), (68, 68), (70, 70)]),
+        (ByteSet::new().bytes(&[1, 3, 5, 7, 9]).range(20..=100),            &[(1, 1), (3, 3), (5, 5), (7, 7), (9, 9), (20, 100)]),
+    ] {
+        for (start, end) in runs {
+            let mut r = Reader::new(&all_bytes);
+            r.seek(*start as usize);
+            assert_eq!(r.read_while(set).len(), (end - start) as usize + 1);
+        }
+    }
+
+    let mut r = Reader::new(b"content-type: text/html");
+    assert_eq!(r.read_while(KEBAB), b"content-type");
+    assert_eq!(r.read_while(&!ByteSet::ALPHABETIC), b": ");
+    assert_eq!(r.read_until(ByteSet::new().bytes(b"/;")), b"text");
+    r.skip_while(!ByteSet::IDENT_START);
+    assert_eq!(r.read_while(ByteSet::IDENT_CONTINUE), b"html");
+}
+
+#[test] fn test_skip_long() {
+    use byte_reader::ByteSet;
+
+    let mut input = [0u8; 70];
+    for stop in 0..=input.len() {
+        for stopper in [b'x', 0x0B, 0x8D, 0xA0, 0x00] {
+            for (i, b) in input.iter_mut().enumerate() {
+                *b = if i < stop {b" \t\n\x0C\r"[i % 5]} else {stopper}
+            }
+            let mut r = Reader::new(&input);
+            r.skip_whitespace();
+            assert_eq!(r.index(), stop);
+            let mut r = Reader::new(&input);
+            r.skip_while(ByteSet::WHITESPACE.union(ByteSet::new().byte(b'+')));
+            assert_eq!(r.index(), stop);
+
+            for (i, b) in input.iter_mut().enumerate() {
+                *b = if i < stop {b'-'} else {stopper}
+            }
+            let mut r = Reader::new(&input);
+            assert_eq!(r.read_while(b'-').len(), stop);
+            assert_eq!(r.read_while(&!ByteSet::new().byte(stopper)).len(), 0);
+            r.seek(0);
+            assert_eq!(r.read_until(ByteSet::new().byte(stopper)).len(), stop);
+        }
+    }
+}
+
+#[test] fn test_advance_long() {
+    use byte_reader::LineIndex;
+
+    let mut input = [b'.'; 300];
+    let mut gap = 0;
+    let mut i = 0; while i < input.len() {
+        input[i] = b'\n';
+        gap = (gap * 7 + 3) % 40;
+        i += gap + 1
+    }
+    let mut storage = [0; 300];
+    let index = LineIndex::build(&input, &mut storage).unwrap();
+
+    for from in (0..=300).step_by(13) {
+        for to in (0..=300).step_by(11) {
+            let mut r = Reader::with_locator(&input, LineColumn::new());
+            r.advance_by(from);
+            r.seek(to);
+            assert_eq!(r.location(), Some(index.locate(to)), "from {from} to {to}");
+        }
+    }
+}
+
+#[test] fn test_finder() {
+    use byte_reader::Finder;
+
+    fn naive(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        (0..=haystack.len()).find(|i| haystack[*i..].starts_with(needle))
+    }
+
+    let mut seed = 42u32;
+    let mut random_bytes = |buf: &mut [u8]| for b in buf.iter_mut() {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        *b = b"aab"[(seed >> 16) as usize % 3]
+    };
+    for (haystack_len, needle_len) in [(0, 1), (5, 0), (16, 1), (16, 2), (32, 3), (64, 5), (64, 8), (128, 13)] {
+        for _ in 0..200 {
+            let (mut haystack, mut needle) = ([0; 128], [0; 13]);
+            let (haystack, needle) = (&mut haystack[..haystack_len], &mut needle[..needle_len]);
+            random_bytes(haystack);
+            random_bytes(needle);
+            assert_eq!(Finder::new(needle).find_index(haystack), naive(haystack, needle), "{needle:?} in {haystack:?}");
+        }
+    }
+    for needle in [&b"aaaa"[..], b"abab", b"abaab", b"baaaa", b"aaaab", b"cab"] {
+        let haystack = b"aaabaaaabaabaabababaaaaabcabab";
+        assert_eq!(Finder::new(needle).find_index(haystack), naive(haystack, needle));
+    }
+
+    const BOUNDARY: Finder = Finder::new(b"\r\n--boundary");
+    let mut r = Reader::new(b"--boundary\r\nfirst\r\n--boundary\r\nsecond\r\n--boundary--");
+    r.consume(&BOUNDARY.needle()[2..]).unwrap();
+    let mut parts = [&b""[..]; 2];
+    for part in &mut parts {
+        *part = r.read_until(&BOUNDARY);
+        r.consume(&BOUNDARY).unwrap();
+    }
+    assert_eq!(parts, [&b"\r\nfirst"[..], b"\r\nsecond"]);
+    assert_eq!(r.remaining(), b"--");
+}
+
+#[test] fn test_read_until_any() {
+    use byte_reader::MultiFinder;
+
+    const SQL_DELIMITERS: MultiFinder<3> = MultiFinder::new([b"\r\n", b";", b"--"]);
+    let mut r = Reader::new(b"SELECT 1; -- comment\r\nSELECT 2");
+    assert_eq!(r.read_until_any(&SQL_DELIMITERS), (&b"SELECT 1"[..], Some(1)));
+    r.consume(&SQL_DELIMITERS).unwrap();
+    assert_eq!(r.read_until_any(&SQL_DELIMITERS), (&b" "[..], Some(2)));
+    r.consume(&SQL_DELIMITERS).unwrap();
+    assert_eq!(r.read_until_any(&SQL_DELIMITERS), (&b" comment"[..], Some(0)));
+    r.consume(&SQL_DELIMITERS).unwrap();
+    assert_eq!(r.read_until_any(&SQL_DELIMITERS), (&b"SELECT 2"[..], None));
+
+    /* leftmost, then first listed */
+    let finder = MultiFinder::<4, 16>::with_capacity([b"c", b"abcd", b"ab", b"a"]);
+    assert_eq!(finder.find_pattern(b"xxabcd"), Some((2, 1)));
+    assert_eq!(finder.find_pattern(b"xxabc"),  Some((2, 2)));
+    assert_eq!(finder.find_pattern(b"xxcab"),  Some((2, 0)));
+    assert_eq!(finder.find_pattern(b"xxx"),    None);
+
+    fn naive<const N: usize>(haystack: &[u8], patterns: &[&[u8]; N]) -> Option<(usize, usize)> {
+        (0..=haystack.len()).find_map(|i| patterns.iter().position(|p| haystack[i..].starts_with(p)).map(|p| (i, p)))
+    }
+    let patterns: [&[u8]; 5] = [b"aba", b"bab", b"abba", b"bb", b"aaab"];
+    let finder = MultiFinder::new(patterns);
+    let mut seed = 7u32;
+    for len in 0..40 {
+        let haystack: Vec<u8> = (0..len).map(|_| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            b"ab"[(seed >> 16) as usize % 2]
+        }).collect();
+        assert_eq!(finder.find_pattern(&haystack), naive(&haystack, &patterns), "{haystack:?}");
+    }
+}
+
+#[test] #[should_panic] fn test_multi_finder_capacity() {
+    byte_reader::MultiFinder::<2, 4>::with_capacity([b"ab", b"cd"]);
+}
+
+#[test] fn test_read_keyword() {
+    use byte_reader::Keywords;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tok {True, Try, Eq, EqEq, Le, Lt, Shl}
+    const TOKENS: Keywords<Tok, 8> = Keywords::new([
+        ("=", Tok::Eq), ("==", Tok::EqEq), ("true", Tok::True), ("try", Tok::Try),
+        ("<", Tok::Lt), ("<=", Tok::Le), ("<<", Tok::Shl), ("=", Tok::EqEq),
+    ]);
+
+    let mut r = Reader::new(b"true==try<<=<tr=");
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::True));
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::EqEq));
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::Try));
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::Shl));
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::Eq));
+    assert_eq!(r.read_keyword(&TOKENS), Some(Tok::Lt));
+    assert_eq!(r.read_keyword(&TOKENS), None);
+    assert_eq!(r.remaining(), b"tr=");
+    r.advance_by(2);
+    assert!(r.starts_with(&TOKENS));
+    assert!(r.consume(&TOKENS).is_some());
+    assert_eq!(r.read_keyword(&TOKENS), None);
+
+    assert_eq!(TOKENS.get("<="), Some(Tok::Le));
+    assert_eq!(TOKENS.get("tr"), None);
+    assert_eq!(TOKENS.get(b"truee"), None);
+    assert_eq!(Keywords::<Tok, 0>::new([]).longest_prefix(b"true"), None);
+}
+
+#[test] fn test_consume_keyword() {
+    use byte_reader::{Keywords, IdentClass, ByteSet};
+
+    let mut r = Reader::new(b"trueish true==x true_ true");
+    assert_eq!(r.consume_keyword("true"), None);
+    assert!(r.consume("trueish ").is_some());
+    assert_eq!(r.consume_keyword("true"), Some(()));
+    assert_eq!(r.consume_keyword("=="), Some(()));
+    assert!(r.consume("x ").is_some());
+    assert_eq!(r.consume_keyword("true"), None);
+    assert!(r.consume("true_ ").is_some());
+    assert_eq!(r.consume_keyword("true"), Some(()));
+    assert_eq!(r.remaining(), b"");
+
+    let kebab = IdentClass::Bytes(ByteSet::IDENT_CONTINUE.bytes(b"-"));
+    let mut r = Reader::new(b"content-type");
+    assert_eq!(r.consume_keyword_by("content", kebab), None);
+    assert_eq!(r.consume_keyword("content"), Some(()));
+
+    let mut r = Reader::new("letあ let→ 変数名".as_bytes());
+    assert_eq!(r.consume_keyword_by("let", IdentClass::UnicodeXid), None);
+    assert_eq!(r.consume_keyword_by("let", IdentClass::Ascii), Some(()));
+    assert!(r.consume("あ ").is_some());
+    assert_eq!(r.consume_keyword_by("let", IdentClass::UnicodeXid), Some(()));
+    assert!(r.consume("→ ").is_some());
+    assert_eq!(r.consume_keyword_by("変数", IdentClass::UnicodeXid), None);
+    assert_eq!(r.consume_keyword_by("変数名", IdentClass::UnicodeXid), Some(()));
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tok {True, Tr, EqEq}
+    const WORDS: Keywords<Tok, 3> = Keywords::new([("true", Tok::True), ("tr", Tok::Tr), ("==", Tok::EqEq)])
+        .whole_words(IdentClass::Ascii);
+    let mut r = Reader::new(b"trueish");
+    assert_eq!(r.read_keyword(&WORDS), None);
+    let mut r = Reader::new(b"true==tr");
+    assert_eq!(r.read_keyword(&WORDS), Some(Tok::True));
+    assert_eq!(r.read_keyword(&WORDS), Some(Tok::EqEq));
+    assert_eq!(r.read_keyword(&WORDS), Some(Tok::Tr));
+}
+
+#[test] fn test_ignore_ascii_case() {
+    use byte_reader::{Keywords, Finder};
+
+    let mut r = Reader::new(b"Content-Type: text/HTML; Charset=UTF-8");
+    assert_eq!(r.consume_ignore_ascii_case("content-length"), None);
+    assert_eq!(r.consume_ignore_ascii_case("content-type"), Some(&b"Content-Type"[..]));
+    assert_eq!(r.read_until_ignore_ascii_case("charset="), b": text/HTML; ");
+    assert_eq!(r.consume_ignore_ascii_case("CHARSET="), Some(&b"Charset="[..]));
+    assert_eq!(r.read_until_ignore_ascii_case(b"x"), b"UTF-8");
+
+    let finder = Finder::new_ignore_ascii_case(b"aBaB");
+    for (haystack, found) in [(&b"xxABAB"[..], Some(2)), (b"abaAbAb", Some(3)), (b"ab@b", None), (b"AB", None)] {
+        assert_eq!(finder.find_index(haystack), found);
+    }
+    assert_eq!(Finder::new_ignore_ascii_case(b"b").find_index(b"aaB"), Some(2));
+    let mut seed = 3u32;
+    let mut random = |buf: &mut [u8]| for b in buf.iter_mut() {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        *b = b"aAbaB"[(seed >> 16) as usize % 5]
+    };
+    for _ in 0..500 {
+        let (mut haystack, mut needle) = ([0; 40], [0; 5]);
+        random(&mut haystack); random(&mut needle);
+        let naive = (0..=35).find(|i| haystack[*i..*i + 5].eq_ignore_ascii_case(&needle));
+        assert_eq!(Finder::new_ignore_ascii_case(&needle).find_index(&haystack), naive);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Sql {Select, From, Fetch}
+    const SQL: Keywords<Sql, 3> = Keywords::new([("SELECT", Sql::Select), ("fetch", Sql::Fetch), ("From", Sql::From)])
+        .ignore_ascii_case();
+    let mut r = Reader::new(b"select FROM Fetch");
+    assert_eq!(r.read_keyword(&SQL), Some(Sql::Select)); r.skip_whitespace();
+    assert_eq!(r.read_keyword(&SQL), Some(Sql::From));   r.skip_whitespace();
+    assert_eq!(r.read_keyword(&SQL), Some(Sql::Fetch));
+    assert_eq!(SQL.get("sElEcT"), Some(Sql::Select));
+
+    let duplicated = Keywords::new([("select", 1), ("SELECT", 2), ("Select", 3)]).ignore_ascii_case();
+    assert_eq!(duplicated.get("select"), Some(1));
+    assert_eq!(duplicated.get("SELECT"), Some(1));
+    let duplicated = Keywords::new([("SELECT", 1), ("select", 2)]).ignore_ascii_case();
+    assert_eq!(duplicated.get("select"), Some(1));
+}
+
+#[test] fn test_expect() {
+    let mut r = Reader::with_locator(b"datasource db {\n  provider = \"postgresql\"\n}", LineColumn::new());
+
+    assert_eq!(r.expect("datasource"), Ok(()));
+    r.skip_whitespace();
+    assert_eq!(r.expect("db"), Ok(()));
+    r.skip_whitespace();
+    assert_eq!(r.expect(b"{"), Ok(()));
+    r.skip_whitespace();
+
+    let e = r.expect_oneof(&["url", "output"]).unwrap_err();
+    assert_eq!(e.expected, Expected::OneOf(&["url", "output"]));
+    assert_eq!(e.found, Some(b'p'));
+    assert_eq!(e.index, 18);
+    assert_eq!(e.location, loc(2, 3));
+    assert_eq!(r.remaining(), b"provider = \"postgresql\"\n}");
+
+    assert_eq!(r.expect_oneof(&["url", "provider"]), Ok(1));
+    r.skip_whitespace();
+
+    let e: ParseError = r.expect(":").unwrap_err();
+    assert_eq!(e.expected, Expected::Token(b":"));
+    assert_eq!(e.found, Some(b'='));
+    assert_eq!(e.to_string(), "expected `:`, but found `=` at line 2, column 12");
+    let e = Reader::new(b"= 1").expect(":").unwrap_err();
+    assert_eq!(e.location, None);
+    assert_eq!(e.to_string(), "expected `:`, but found `=` at index 0");
+
+    r.read_until("}");
+    r.advance_by(1);
+    let e = r.expect("}").unwrap_err();
+    assert_eq!(e.found, None);
+    assert_eq!(e.index, 43);
+}
+
+#[test] fn test_span() {
+    let input = b"model Post {\n  title String\n}";
+    let mut r = Reader::with_locator(input, LineColumn::new());
+
+    let (model, span) = r.read_while_spanned(|b: &u8| b.is_ascii_alphabetic());
+    assert_eq!(model, b"model");
+    assert_eq!((span.start, span.end, span.len()), (0, 5, 5));
+    assert_eq!(&input[span.range()], b"model");
+    assert_eq!((span.start_location, span.end_location), (loc(1, 1), loc(1, 6)));
+
+    let (_, span) = r.read_until_spanned("title");
+    assert_eq!(&input[span.range()], b" Post {\n  ");
+    assert_eq!((span.start_location, span.end_location), (loc(1, 6), loc(2, 3)));
+
+    let title = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+    r.skip_whitespace();
+    let string = r.read_while(|b: &u8| b.is_ascii_alphabetic());
+    r.read_until("}");
+
+    let span = r.span_of(title).unwrap();
+    assert_eq!(span.range(), 15..20);
+    assert_eq!((span.start_location, span.end_location), (loc(2, 3), loc(2, 8)));
+    let span = r.span_of(string).unwrap();
+    assert_eq!(span.range(), 21..27);
+    assert_eq!((span.start_location, span.end_location), (loc(2, 9), loc(2, 15)));
+    let span = r.span_of(&input[29..]).unwrap();
+    assert!(span.is_empty());
+    assert_eq!(span.start_location, loc(3, 2));
+
+    assert_eq!(r.span_of(b"title"), None);
+
+    let start = r.checkpoint();
+    r.unwind_by(16);
+    let span: Span = r.span_since(start);
+    assert_eq!(span.range(), 12..28);
+    assert_eq!((span.start_location, span.end_location), (loc(1, 13), loc(3, 1)));
 }
 
 #[test] fn detached_ref() {
@@ -214,15 +888,15 @@ use byte_reader::Reader;
     assert_eq!(r.read_int(), None);
     assert_eq!(r.remaining(), b"-a");
 
-    let mut r = Reader::new(b"\
+    let mut r = Reader::with_locator(b"\
         model Post {\n\
           title     String @db.VarChar(200)\n\
           n_authors Int    @default(1)\n\
           z_flag    Int    @default(-42)\n\
         }\
-    ");
+    ", LineColumn::new());
 
-    #[cfg(feature="location")] assert_eq!(r.line, 1);
+    assert_eq!(r.line(), 1);
     assert!(r.consume("model").is_some());
     r.skip_whitespace();
     assert_eq!(r.read_snake().unwrap(), "Post");
@@ -230,7 +904,7 @@ use byte_reader::Reader;
     assert_eq!(r.peek().unwrap(), &b'{'); r.advance_by(1);
     r.skip_whitespace();
 
-    #[cfg(feature="location")] assert_eq!(r.line, 2);
+    assert_eq!(r.line(), 2);
     assert_eq!(r.read_snake().unwrap(), "title");
     r.skip_whitespace();
     assert_eq!(r.read_snake().unwrap(), "String");
@@ -247,7 +921,7 @@ use byte_reader::Reader;
 
     r.skip_whitespace();
 
-    #[cfg(feature="location")] assert_eq!(r.line, 3);
+    assert_eq!(r.line(), 3);
     assert_eq!(r.read_snake().unwrap(), "n_authors");
     r.skip_whitespace();
     assert_eq!(r.read_snake().unwrap(), "Int");
@@ -262,7 +936,7 @@ use byte_reader::Reader;
 
     r.skip_whitespace();
 
-    #[cfg(feature="location")] assert_eq!(r.line, 4);
+    assert_eq!(r.line(), 4);
     assert_eq!(r.read_snake().unwrap(), "z_flag");
     r.skip_whitespace();
     assert_eq!(r.read_snake().unwrap(), "Int");
@@ -277,7 +951,436 @@ use byte_reader::Reader;
 
     r.skip_whitespace();
 
-    #[cfg(feature="location")] assert_eq!(r.line, 5);
+    assert_eq!(r.line(), 5);
     assert_eq!(r.peek().unwrap(), &b'}'); r.advance_by(1);
     assert_eq!(r.peek(), None)
 }
+
+#[cfg(feature="text")]
+#[test] fn test_read_integer() {
+    use byte_reader::{NumberError, NumberErrorKind};
+
+    let mut r = Reader::new(b"255 256 -1");
+    assert_eq!(r.read_integer::<u8>(), Ok(255));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer::<u8>(), Err(NumberError {
+        kind: NumberErrorKind::Overflow,
+        span: Span { start: 4, end: 7, start_location: None, end_location: None },
+    }));
+    assert_eq!(r.remaining(), b"256 -1");
+    assert_eq!(r.read_integer::<u16>(), Ok(256));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer::<u32>().map_err(|e| e.kind), Err(NumberErrorKind::NoDigits));
+    assert_eq!(r.remaining(), b"-1");
+
+    let mut r = Reader::new(b"-128,-129,127,128");
+    assert_eq!(r.read_integer::<i8>(), Ok(i8::MIN));
+    r.advance_by(1);
+    assert_eq!(r.read_integer::<i8>().map_err(|e| e.span.range()), Err(5..9));
+    assert_eq!(r.read_integer::<i16>(), Ok(-129));
+    r.advance_by(1);
+    assert_eq!(r.read_integer::<i8>(), Ok(i8::MAX));
+    r.advance_by(1);
+    assert_eq!(r.read_integer::<i8>().map_err(|e| e.kind), Err(NumberErrorKind::Overflow));
+    assert_eq!(r.read_integer::<i128>(), Ok(128));
+
+    let mut r = Reader::new(b"340282366920938463463374607431768211455 340282366920938463463374607431768211456");
+    assert_eq!(r.read_integer::<u128>(), Ok(u128::MAX));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer::<u128>().map_err(|e| e.kind), Err(NumberErrorKind::Overflow));
+
+    let mut r = Reader::with_locator(b"\n  99999999999999999999", LineColumn::new());
+    r.skip_whitespace();
+    let error = r.read_integer::<u64>().unwrap_err();
+    assert_eq!(error.span.start_location, loc(2, 3));
+    assert_eq!(error.span.end_location,   loc(2, 23));
+    assert_eq!(r.read_uint(), None);
+    assert_eq!(r.remaining(), b"99999999999999999999");
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_integer_literal() {
+    use byte_reader::{IntegerLiteral, NumberErrorKind};
+
+    let mut r = Reader::new(b"0xFF 0o755 0b1010 1_000_000 +42 10u8 -3i64 0x_ff_u16");
+    assert_eq!(r.read_integer_literal::<u8>(), Ok(IntegerLiteral { value: 255, radix: 16, suffix: None }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u16>(), Ok(IntegerLiteral { value: 0o755, radix: 8, suffix: None }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u8>(), Ok(IntegerLiteral { value: 10, radix: 2, suffix: None }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u32>(), Ok(IntegerLiteral { value: 1_000_000, radix: 10, suffix: None }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<i32>(), Ok(IntegerLiteral { value: 42, radix: 10, suffix: None }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u64>(), Ok(IntegerLiteral { value: 10, radix: 10, suffix: Some("u8") }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<i64>(), Ok(IntegerLiteral { value: -3, radix: 10, suffix: Some("i64") }));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u16>(), Ok(IntegerLiteral { value: 255, radix: 16, suffix: Some("u16") }));
+    assert!(r.remaining().is_empty());
+
+    /* overflowing `T` or the suffix type */
+    let mut r = Reader::new(b"0x1_00 300u8 -3u8 -0x80i8");
+    assert_eq!(r.read_integer_literal::<u8>().map_err(|e| (e.kind, e.span.range())), Err((NumberErrorKind::Overflow, 0..6)));
+    assert_eq!(r.read_integer_literal::<u16>().map(|l| l.value), Ok(256));
+    r.skip_whitespace();
+    assert_eq!(r.read_integer_literal::<u32>().map_err(|e| (e.kind, e.span.range())), Err((NumberErrorKind::Overflow, 7..12)));
+    assert_eq!(r.remaining(), b"300u8 -3u8 -0x80i8");
+    r.advance_by(6);
+    assert_eq!(r.read_integer_literal::<i32>().map_err(|e| e.kind), Err(NumberErrorKind::Overflow));
+    r.advance_by(5);
+    assert_eq!(r.read_integer_literal::<i32>().map(|l| l.value), Ok(-128));
+
+    /* not an integer literal */
+    for input in [&b"0x"[..], b"0bz", b"_1", b"+_1", b"-1", b"u8"] {
+        let mut r = Reader::new(input);
+        assert_eq!(r.read_integer_literal::<u32>().map_err(|e| e.kind), Err(NumberErrorKind::NoDigits));
+        assert_eq!(r.remaining(), input);
+    }
+
+    /* suffix not ending as a word, or not a suffix */
+    let mut r = Reader::new(b"10u8x 0b10i");
+    assert_eq!(r.read_integer_literal::<u8>(), Ok(IntegerLiteral { value: 10, radix: 10, suffix: None }));
+    assert_eq!(r.remaining(), b"u8x 0b10i");
+    r.advance_by(4);
+    assert_eq!(r.read_integer_literal::<u8>().map(|l| l.value), Ok(0b10));
+    assert_eq!(r.remaining(), b"i");
+
+    /* digit out of the radix */
+    for (input, span) in [(&b"0b12"[..], 0..4), (b"0o8", 0..3), (b"-0o1_9", 0..6), (b"0b1f", 0..4)] {
+        let mut r = Reader::new(input);
+        assert_eq!(r.read_integer_literal::<i32>().map_err(|e| (e.kind, e.span.range())), Err((NumberErrorKind::InvalidDigit, span)));
+        assert_eq!(r.remaining(), input);
+    }
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_float_syntax() {
+    use byte_reader::{FloatSyntax, NumberErrorKind};
+
+    fn read(input: &str, syntax: FloatSyntax) -> Option<(f64, &str)> {
+        let mut r = Reader::new(input.as_bytes());
+        let value = r.read_float::<f64>(syntax).ok();
+        if value.is_none() {assert_eq!(r.remaining(), input.as_bytes())}
+        value.map(|value| (value, &input[input.len() - r.remaining().len()..]))
+    }
+
+    for (input, json, rust, c) in [
+        ("2.75",       Some((2.75, "")),    Some((2.75, "")),       Some((2.75, ""))),
+        ("-1e-9,",     Some((-1e-9, ",")),  Some((-1e-9, ",")),     Some((-1e-9, ","))),
+        ("+2.5",       None,                Some((2.5, "")),        Some((2.5, ""))),
+        ("42",         Some((42., "")),     Some((42., "")),        Some((42., ""))),
+        ("007",        Some((0., "07")),    Some((7., "")),         Some((7., ""))),
+        (".5",         None,                None,                   Some((0.5, ""))),
+        ("5.",         Some((5., ".")),     Some((5., "")),         Some((5., ""))),
+        ("5.x",        Some((5., ".x")),    Some((5., ".x")),       Some((5., "x"))),
+        ("1..2",       Some((1., "..2")),   Some((1., "..2")),      Some((1., ".2"))),
+        ("1.e5",       Some((1., ".e5")),   Some((1., ".e5")),      Some((1e5, ""))),
+        ("1e",         Some((1., "e")),     Some((1., "e")),        Some((1., "e"))),
+        ("2E+3]",      Some((2e3, "]")),    Some((2e3, "]")),       Some((2e3, "]"))),
+        ("1_000.5_",   Some((1., "_000.5_")), Some((1000.5, "")),   Some((1., "_000.5_"))),
+        ("1e1_0",      Some((10., "_0")),   Some((1e10, "")),       Some((10., "_0"))),
+        ("2f32",       Some((2., "f32")),   Some((2., "")),         Some((2., "f32"))),
+        ("2.5f64 ",    Some((2.5, "f64 ")), Some((2.5, " ")),       Some((2.5, "f64 "))),
+        ("1.5f;",      Some((1.5, "f;")),   Some((1.5, "f;")),      Some((1.5, ";"))),
+        ("1f",         Some((1., "f")),     Some((1., "f")),        Some((1., "f"))),
+        ("inf",        None,                Some((f64::INFINITY, "")),     Some((f64::INFINITY, ""))),
+        ("-Infinity]", None,                Some((f64::NEG_INFINITY, "]")), Some((f64::NEG_INFINITY, "]"))),
+        ("info",       None,                None,                   None),
+        ("-",          None,                None,                   None),
+        ("e5",         None,                None,                   None),
+        ("1e400",      Some((f64::INFINITY, "")), Some((f64::INFINITY, "")), Some((f64::INFINITY, ""))),
+        ("1e-400",     Some((0., "")),      Some((0., "")),         Some((0., ""))),
+    ] {
+        assert_eq!(read(input, FloatSyntax::Json), json, "{input:?} in JSON");
+        assert_eq!(read(input, FloatSyntax::Rust), rust, "{input:?} in Rust");
+        assert_eq!(read(input, FloatSyntax::C),    c,    "{input:?} in C");
+    }
+
+    let mut r = Reader::new(b"NaN -nan");
+    assert!(r.read_float::<f32>(FloatSyntax::Rust).unwrap().is_nan());
+    r.skip_whitespace();
+    assert!(r.read_float::<f32>(FloatSyntax::C).unwrap().is_nan());
+    assert!(r.remaining().is_empty());
+
+    let mut r = Reader::new(b"\n  NaN");
+    r.skip_whitespace();
+    assert_eq!(r.read_float::<f64>(FloatSyntax::Json).map_err(|e| (e.kind, e.span.range())), Err((NumberErrorKind::NoDigits, 3..3)));
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_decimal() {
+    use byte_reader::{Decimal, FloatSyntax, NumberErrorKind};
+
+    let mut r = Reader::new(b"123.4500 -1.2e3 +0.5e-2 1_000.25_0");
+    let price = r.read_decimal(FloatSyntax::C).unwrap();
+    assert_eq!(price, Decimal { negative: false, integer: "123", fraction: "4500", exponent: 0 });
+    assert_eq!(price.to_i128(), Some((1234500, 4)));
+    assert_eq!(price.to_i128_with_scale(2), Some(12345));
+    assert_eq!(price.to_i128_with_scale(6), Some(123450000));
+    assert_eq!(price.to_i128_with_scale(1), None);
+    r.skip_whitespace();
+
+    let d = r.read_decimal(FloatSyntax::C).unwrap();
+    assert_eq!(d, Decimal { negative: true, integer: "1", fraction: "2", exponent: 3 });
+    assert_eq!(d.to_i128(), Some((-1200, 0)));
+    assert_eq!(d.to_i128_with_scale(3), Some(-1200000));
+    r.skip_whitespace();
+
+    let d = r.read_decimal(FloatSyntax::C).unwrap();
+    assert_eq!(d.to_i128(), Some((5, 3)));
+    assert_eq!(d.to_i128_with_scale(2), None);
+    r.skip_whitespace();
+
+    let d = r.read_decimal(FloatSyntax::Rust).unwrap();
+    assert_eq!(d, Decimal { negative: false, integer: "1_000", fraction: "25_0", exponent: 0 });
+    assert_eq!(d.to_i128(), Some((1000250, 3)));
+    assert!(r.remaining().is_empty());
+
+    /* overflow checks */
+    let mut r = Reader::new(b"-170141183460469231731687303715884105728 170141183460469231731687303715884105728 1e39 0e99999");
+    assert_eq!(r.read_decimal(FloatSyntax::Json).unwrap().to_i128(), Some((i128::MIN, 0)));
+    r.skip_whitespace();
+    assert_eq!(r.read_decimal(FloatSyntax::Json).unwrap().to_i128(), None);
+    r.skip_whitespace();
+    let d = r.read_decimal(FloatSyntax::Json).unwrap();
+    assert_eq!(d.to_i128(), None);
+    assert_eq!(d.to_i128_with_scale(0), None);
+    r.skip_whitespace();
+    assert_eq!(r.read_decimal(FloatSyntax::Json).unwrap().to_i128(), Some((0, 0)));
+
+    /* saturated exponent */
+    let mut r = Reader::new(b"1e-65535 1e-99999999999 0.0e-99999999999 1e99999999999");
+    assert_eq!(r.read_decimal(FloatSyntax::Json).unwrap().to_i128(), Some((1, 65535)));
+    r.skip_whitespace();
+    let d = r.read_decimal(FloatSyntax::Json).unwrap();
+    assert_eq!(d.exponent, -65536);
+    assert_eq!(d.to_i128(), None);
+    assert_eq!(d.to_i128_with_scale(99999), None);
+    r.skip_whitespace();
+    assert_eq!(r.read_decimal(FloatSyntax::Json).unwrap().to_i128_with_scale(2), Some(0));
+    r.skip_whitespace();
+    let d = r.read_decimal(FloatSyntax::Json).unwrap();
+    assert_eq!(d.exponent, 65536);
+    assert_eq!(d.to_i128(), None);
+
+    let mut r = Reader::new(b"inf");
+    assert_eq!(r.read_decimal(FloatSyntax::Rust).map_err(|e| e.kind), Err(NumberErrorKind::NoDigits));
+    assert_eq!(r.remaining(), b"inf");
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_escaped() {
+    use byte_reader::{Escapes, StringErrorKind};
+
+    fn unescaped(input: &[u8], quote: u8, escapes: Escapes) -> Result<(Vec<u8>, &[u8]), StringErrorKind> {
+        let mut r = Reader::new(input);
+        match r.read_escaped_by(quote, escapes) {
+            Ok(escaped) => Ok((escaped.unescaped().collect(), &input[input.len() - r.remaining().len()..])),
+            Err(e) => {assert_eq!(r.remaining(), input); Err(e.kind)}
+        }
+    }
+
+    /* JSON */
+    assert_eq!(unescaped(br#""say \"hi\"", 1"#, b'"', Escapes::JSON), Ok((br#"say "hi""#.to_vec(), &b", 1"[..])));
+    assert_eq!(unescaped(br#""a\\b\/c\n\t\u00e9\ud83d\ude00""#, b'"', Escapes::JSON), Ok(("a\\b/c\n\t\u{e9}\u{1F600}".as_bytes().to_vec(), &b""[..])));
+    assert_eq!(unescaped(br#""\x41""#, b'"', Escapes::JSON), Err(StringErrorKind::InvalidEscape));
+    assert_eq!(unescaped(br#""\ud83d""#, b'"', Escapes::JSON), Err(StringErrorKind::InvalidEscape));
+    assert_eq!(unescaped(br#""\ude00""#, b'"', Escapes::JSON), Err(StringErrorKind::InvalidEscape));
+    assert_eq!(unescaped(br#""\u12g4""#, b'"', Escapes::JSON), Err(StringErrorKind::InvalidEscape));
+
+    /* Rust */
+    assert_eq!(unescaped(b"\"\\x41\\0\\'\\u{1F600}\\u{e_9}\"", b'"', Escapes::RUST), Ok(("A\0'\u{1F600}\u{e9}".as_bytes().to_vec(), &b""[..])));
+    assert_eq!(unescaped(b"\"line \\\n    continued\"", b'"', Escapes::RUST), Ok((b"line continued".to_vec(), &b""[..])));
+    assert_eq!(unescaped(b"'\\''", b'\'', Escapes::RUST), Ok((b"'".to_vec(), &b""[..])));
+    for invalid in [&b"\"\\x80\""[..], b"\"\\u{}\"", b"\"\\u{1234567}\"", b"\"\\u{D800}\"", b"\"\\u{_1}\"", b"\"\\a\""] {
+        assert_eq!(unescaped(invalid, b'"', Escapes::RUST), Err(StringErrorKind::InvalidEscape), "{:?}", std::str::from_utf8(invalid));
+    }
+
+    /* C */
+    assert_eq!(unescaped(b"\"\\101\\0\\x7f\\xFF\\a\\v\\?\\u00e9\\U0001F600\"", b'"', Escapes::C),
+        Ok(([&b"A\0\x7f\xff\x07\x0b?"[..], "\u{e9}\u{1F600}".as_bytes()].concat(), &b""[..])));
+    assert_eq!(unescaped(b"\"\\400\"", b'"', Escapes::C), Err(StringErrorKind::InvalidEscape));
+    assert_eq!(unescaped(b"\"\\x\"", b'"', Escapes::C), Err(StringErrorKind::InvalidEscape));
+
+    /* SQL */
+    assert_eq!(unescaped(b"'it''s' AND", b'\'', Escapes::SQL), Ok((b"it's".to_vec(), &b" AND"[..])));
+    assert_eq!(unescaped(b"'a\\b'", b'\'', Escapes::SQL), Ok((b"a\\b".to_vec(), &b""[..])));
+    assert_eq!(unescaped(b"''''", b'\'', Escapes::SQL), Ok((b"'".to_vec(), &b""[..])));
+
+    /* custom escape character */
+    assert_eq!(unescaped(b"\"a`\"b``\\n\"", b'"', Escapes::JSON.escape_char(b'`')), Ok((b"a\"b`\\n".to_vec(), &b""[..])));
+
+    /* not a string */
+    assert_eq!(unescaped(b"abc", b'"', Escapes::JSON), Err(StringErrorKind::NotQuoted));
+    assert_eq!(unescaped(b"\"abc", b'"', Escapes::JSON), Err(StringErrorKind::Unterminated));
+    assert_eq!(unescaped(b"\"abc\\\"", b'"', Escapes::JSON), Err(StringErrorKind::Unterminated));
+    assert_eq!(unescaped(b"'abc''", b'\'', Escapes::SQL), Err(StringErrorKind::Unterminated));
+    assert_eq!(unescaped(b"\"abc\\", b'"', Escapes::RUST), Err(StringErrorKind::Unterminated));
+    assert_eq!(unescaped(b"\"\\q abc", b'"', Escapes::JSON), Err(StringErrorKind::Unterminated));
+
+    /* invalid sequence running into the closing quote */
+    for (input, span) in [(&br#""\u12""#[..], 1..5), (br#""\ud83d\u00""#, 1..11), (br#""\u12" "#, 1..5)] {
+        let mut r = Reader::new(input);
+        let error = r.read_escaped_by(b'"', Escapes::JSON).unwrap_err();
+        assert_eq!((error.kind, error.span.range()), (StringErrorKind::InvalidEscape, span), "{:?}", std::str::from_utf8(input));
+    }
+
+    /* raw slice and spans */
+    let mut r = Reader::with_locator(b"key = \"a\\qb\"\nname = \"x\\ty\"", LineColumn::new());
+    r.advance_by(6);
+    let error = r.read_escaped_by(b'"', Escapes::RUST).unwrap_err();
+    assert_eq!((error.span.range(), error.span.start_location), (8..10, loc(1, 9)));
+    r.advance_by(7);
+    r.skip_whitespace();
+    r.advance_by(7);
+    let escaped = r.read_escaped_by(b'"', Escapes::RUST).unwrap();
+    assert_eq!(escaped.raw(), b"x\\ty");
+    assert!(escaped.has_escapes());
+    assert_eq!(escaped.unescaped().collect::<Vec<_>>(), b"x\ty");
+    assert!(r.remaining().is_empty());
+}
+
+#[cfg(feature="text")]
+#[test] fn test_unescape_into() {
+    use byte_reader::{Escapes, UnescapeError};
+
+    let mut r = Reader::new(r#""café \"au lait\"" "\u00ff" "plain""#.as_bytes());
+    let escaped = r.read_escaped_by(b'"', Escapes::JSON).unwrap();
+    let mut buf = [0; 32];
+    assert_eq!(escaped.unescape_into(&mut buf), Ok("café \"au lait\""));
+    assert_eq!(escaped.unescape_into(&mut [0; 8]), Err(UnescapeError::BufferTooSmall { needed: 15 }));
+
+    r.skip_whitespace();
+    let escaped = r.read_escaped_by(b'"', Escapes::JSON).unwrap();
+    assert_eq!(escaped.unescape_into(&mut buf), Ok("\u{ff}"));
+
+    r.skip_whitespace();
+    let escaped = r.read_escaped_by(b'"', Escapes::JSON).unwrap();
+    assert!(!escaped.has_escapes());
+    assert_eq!(escaped.unescape_into(&mut buf), Ok("plain"));
+    assert_eq!(escaped.unescape_into(&mut []), Err(UnescapeError::BufferTooSmall { needed: 5 }));
+
+    let mut r = Reader::new(b"'\\xff'");
+    let escaped = r.read_escaped_by(b'\'', Escapes::C).unwrap();
+    assert_eq!(escaped.unescape_into(&mut buf), Err(UnescapeError::InvalidUtf8));
+}
+
+#[cfg(all(feature="text", feature="alloc"))]
+#[test] fn test_unescape_cow() {
+    use std::borrow::Cow;
+    use byte_reader::{Escapes, UnescapeError};
+
+    let mut r = Reader::new(b"'plain' 'it''s' '\xff'");
+    let plain = r.read_escaped_by(b'\'', Escapes::SQL).unwrap();
+    assert!(matches!(plain.unescape(), Ok(Cow::Borrowed("plain"))));
+    assert!(matches!(plain.unescape_bytes(), Cow::Borrowed(b"plain")));
+
+    r.skip_whitespace();
+    let escaped = r.read_escaped_by(b'\'', Escapes::SQL).unwrap();
+    assert_eq!(escaped.unescape(), Ok(Cow::Owned::<str>(String::from("it's"))));
+    assert!(matches!(escaped.unescape_bytes(), Cow::Owned(bytes) if bytes == b"it's"));
+
+    r.skip_whitespace();
+    let invalid = r.read_escaped_by(b'\'', Escapes::SQL).unwrap();
+    assert_eq!(invalid.unescape(), Err(UnescapeError::InvalidUtf8));
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_float_rounding() {
+    use byte_reader::FloatSyntax;
+
+    fn check(input: &str) {
+        let mut r = Reader::new(input.as_bytes());
+        assert_eq!(r.read_float::<f64>(FloatSyntax::Rust).map(f64::to_bits), Ok(input.parse::<f64>().unwrap().to_bits()), "{input} as f64");
+        assert!(r.remaining().is_empty());
+        let mut r = Reader::new(input.as_bytes());
+        assert_eq!(r.read_float::<f32>(FloatSyntax::Rust).map(f32::to_bits), Ok(input.parse::<f32>().unwrap().to_bits()), "{input} as f32");
+    }
+
+    for input in [
+        "0", "-0", "0.1", "0.3", "1e23", "8.98846567431158e307", "1.7976931348623157e308", "1.7976931348623159e308",
+        "2.2250738585072011e-308", "2.2250738585072012e-308", "4.9406564584124654e-324", "2.4703282292062327e-324",
+        "2.4703282292062328e-324", "9007199254740993", "9007199254740992.000000000000000000000000001",
+        "3.4028235e38", "3.4028236e38", "1.17549435e-38", "1.4e-45", "7.0e-46", "16777217", "16777216.00000001",
+        "123456789012345678901234567890e-10", "0.000000000000000000000000000000000000000000001e300",
+        "2.225073858507201136057409796709131975934819546351645648023426109724822222021076945516529523908135087914149158913039621106870086438694594645527657207407820621743379988141063267329253552286881372149012981122451451889849057222307285255133155755015914397476397983411801999323962548289017107081850690630666655994938275772572015763062690663332647565300009245888316433037779791869612049497390377829704905051080609940730262937128958950003583799967207254304360284078895771796150945516748243471030702609144621572289880258182545180325707018860872113128079512233426288368622321503775666622503982534335974568884423900265498198385487948292206894721689831099698365846814022854243330660339850886445804001034933970427567186443383770486037861622771738545623065874679014086723327636718751234567890123456789012345678901e-308",
+    ] {
+        check(input)
+    }
+
+    /* random bit patterns and digit strings */
+    let mut state = 0x2545F4914F6CDD1Du64;
+    let mut next = move || {state ^= state << 13; state ^= state >> 7; state ^= state << 17; state};
+    for _ in 0..20000 {
+        let bits = next();
+        let float = f64::from_bits(bits & !(1 << 63));
+        if float.is_finite() {
+            check(&format!("{float:e}"));
+            check(&format!("{:.17e}", float));
+        }
+        let n_digits = (next() % 40 + 1) as usize;
+        let digits: String = (0..n_digits).map(|_| char::from(b'0' + (next() % 10) as u8)).collect();
+        let point = (next() % (n_digits as u64 + 1)) as usize;
+        let exponent = (next() % 700) as i64 - 350;
+        check(&format!("0{}.{}0e{exponent}", &digits[..point], &digits[point..]));
+    }
+}
+
+#[cfg(feature="text")]
+#[test] fn test_expect_text() {
+    let mut r = Reader::new(b"Int @default(-a)");
+
+    assert_eq!(r.expect_snake(), Ok("Int"));
+    let e = r.expect_snake().unwrap_err();
+    assert_eq!(e.expected, Expected::Description("snake_case word"));
+    assert_eq!(e.found, Some(b' '));
+    r.skip_whitespace();
+    r.expect("@").unwrap();
+    assert_eq!(r.expect_camel(), Ok("default"));
+    assert_eq!(r.expect_quoted_by(b'(', b')'), Ok(&b"-a"[..]));
+
+    let mut r = Reader::with_locator(b"-a", LineColumn::new());
+    let e = r.expect_int().unwrap_err();
+    assert_eq!(e.to_string(), "expected integer, but found `-` at line 1, column 1");
+    assert_eq!(r.remaining(), b"-a");
+
+    let mut r = Reader::new(b"42");
+    assert_eq!(r.expect_uint(), Ok(42));
+    assert_eq!(r.expect_uint().unwrap_err().found, None);
+}
+
+#[cfg(feature="text")]
+#[test] fn test_read_spanned() {
+    let mut r = Reader::with_locator(b"url = env(\n  \"DATABASE_URL\"\n)", LineColumn::new());
+
+    let (url, span) = r.read_snake_spanned().unwrap();
+    assert_eq!((url, span.range()), ("url", 0..3));
+    assert!(r.read_kebab_spanned().is_none());
+    r.consume(" = ").unwrap();
+    let (env, span) = r.read_camel_spanned().unwrap();
+    assert_eq!((env, span.range()), ("env", 6..9));
+    r.consume("(").unwrap();
+    r.skip_whitespace();
+
+    let (string, span) = r.read_quoted_by_spanned(b'"', b'"').unwrap();
+    assert_eq!((string, span.range()), (&b"DATABASE_URL"[..], 14..26));
+    assert_eq!((span.start_location, span.end_location), (loc(2, 4), loc(2, 16)));
+    assert_eq!(r.remaining(), b"\n)");
+}
+
+#[cfg(feature="alloc")]
+#[test] fn test_owned_patterns() {
+    let (s, v) = (String::from("a,"), Vec::from(*b"b;"));
+
+    let mut r = Reader::new(b"a,b;a,a,b;c");
+    assert!(r.consume(&s).is_some());
+    assert!(r.consume(&v).is_some());
+    assert_eq!(r.consume_oneof([&s, &s]), Some(0));
+    r.skip_while(s.clone());
+    assert_eq!(r.read_until(v.clone()), b"");
+    assert!(r.consume(v).is_some());
+    assert_eq!(r.read_until(s), b"c");
+    assert_eq!(r.remaining(), b"");
+}